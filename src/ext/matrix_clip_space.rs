@@ -1,20 +1,47 @@
-use crate::mat4;
+use crate::{mat4, Float, SignedNumber};
 
-macro_rules! ortho_impl {
-    ($($t:ty)*) => ($(
-        impl mat4<$t> {
-            #[inline]
-            pub const fn ortho(left: $t, right: $t, bottom: $t, top: $t) -> Self {
-                let mut result = Self::identity();
-                result.value[0].x = 2 as $t / (right - left);
-                result.value[1].y = 2 as $t / (top - bottom);
-                result.value[2].z = - 1 as $t;
-                result.value[3].x = - (right + left) / (right - left);
-                result.value[3].y = - (top + bottom) / (top - bottom);
-                result
-            }
-        }
-    )*)
+impl<T: SignedNumber> mat4<T> {
+    #[inline]
+    pub fn ortho(left: T, right: T, bottom: T, top: T) -> Self {
+        let two = T::ONE + T::ONE;
+        let mut result = Self::identity();
+        result.value[0].x = two / (right - left);
+        result.value[1].y = two / (top - bottom);
+        result.value[2].z = - T::ONE;
+        result.value[3].x = - (right + left) / (right - left);
+        result.value[3].y = - (top + bottom) / (top - bottom);
+        result
+    }
 }
 
-ortho_impl! { isize i8 i16 i32 i64 i128 f32 f64 }
+// Right handed, looking down -z, with fovy in radians. The ZO variant maps
+// depth to [0, 1], the NO variant to [-1, 1].
+impl<T: Float> mat4<T> {
+    #[inline]
+    pub fn perspective_rh_zo(fovy: T, aspect: T, near: T, far: T) -> Self {
+        let two = T::ONE + T::ONE;
+        let tan_half_fovy = (fovy / two).tan();
+        let mut result = Self::identity();
+        result.value[0].x = T::ONE / (aspect * tan_half_fovy);
+        result.value[1].y = T::ONE / tan_half_fovy;
+        result.value[2].z = far / (near - far);
+        result.value[2].w = - T::ONE;
+        result.value[3].z = - (far * near) / (far - near);
+        result.value[3].w = T::ZERO;
+        result
+    }
+
+    #[inline]
+    pub fn perspective_rh_no(fovy: T, aspect: T, near: T, far: T) -> Self {
+        let two = T::ONE + T::ONE;
+        let tan_half_fovy = (fovy / two).tan();
+        let mut result = Self::identity();
+        result.value[0].x = T::ONE / (aspect * tan_half_fovy);
+        result.value[1].y = T::ONE / tan_half_fovy;
+        result.value[2].z = - (far + near) / (far - near);
+        result.value[2].w = - T::ONE;
+        result.value[3].z = - (two * far * near) / (far - near);
+        result.value[3].w = T::ZERO;
+        result
+    }
+}
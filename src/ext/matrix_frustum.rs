@@ -0,0 +1,246 @@
+use crate::{mat4, vec3, vec4, Aabb, Float, Frustum, Plane, Sphere};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Containment {
+    Outside,
+    Intersecting,
+    Inside,
+}
+
+#[inline]
+fn row<T: Float>(m: &mat4<T>, i: usize) -> vec4<T> {
+    vec4::new(m.value[0].0[i], m.value[1].0[i], m.value[2].0[i], m.value[3].0[i])
+}
+
+// An infinite far plane has a zero normal, and becomes a plane that every
+// point is infinitely far in front of. So does a zero row, which only a
+// degenerate matrix has and which holds for every point, while a negative
+// distance holds for none.
+#[inline]
+fn plane<T: Float>(v: vec4<T>) -> Plane<T> {
+    let plane = Plane::from(v);
+    if plane.normal.dot(plane.normal) == T::ZERO {
+        Plane::new(plane.normal, if plane.distance >= T::ZERO { T::INFINITY } else { T::NEG_INFINITY })
+    } else {
+        plane.normalize()
+    }
+}
+
+// Gribb and Hartmann: a point is inside when -w <= x <= w, -w <= y <= w and
+// the depth range holds in clip space, each of which is a plane in the rows
+// of the matrix. With a view projection matrix the planes are in world
+// space, with a projection matrix alone in view space.
+#[inline]
+fn from_rows<T: Float>(m: &mat4<T>, near: vec4<T>, far: vec4<T>) -> Frustum<T> {
+    let (x, y, w) = (row(m, 0), row(m, 1), row(m, 3));
+    Frustum::new([w + x, w - x, w + y, w - y, near, far].map(plane))
+}
+
+impl<T: Float> Frustum<T> {
+    // Depth in [0, 1] with 0 at the near plane.
+    #[inline]
+    pub fn from_matrix_zo(m: &mat4<T>) -> Self {
+        from_rows(m, row(m, 2), row(m, 3) - row(m, 2))
+    }
+
+    // Depth in [-1, 1] with -1 at the near plane.
+    #[inline]
+    pub fn from_matrix_no(m: &mat4<T>) -> Self {
+        from_rows(m, row(m, 3) + row(m, 2), row(m, 3) - row(m, 2))
+    }
+
+    // Depth in [0, 1] with 1 at the near plane, possibly with the far plane
+    // at infinity.
+    #[inline]
+    pub fn from_matrix_reversed_zo(m: &mat4<T>) -> Self {
+        from_rows(m, row(m, 3) - row(m, 2), row(m, 2))
+    }
+
+    // Boundaries included.
+    #[inline]
+    pub fn contains_point(&self, point: vec3<T>) -> bool {
+        self.planes.iter().all(|plane| plane.signed_distance(point) >= T::ZERO)
+    }
+
+    // A NaN distance, from infinite coordinates, counts as intersecting.
+    #[inline]
+    pub fn test_sphere(&self, sphere: &Sphere<T>) -> Containment {
+        let mut result = Containment::Inside;
+        for plane in &self.planes {
+            let distance = plane.signed_distance(sphere.center);
+            if distance < -sphere.radius {
+                return Containment::Outside;
+            }
+            if distance >= sphere.radius {
+                continue;
+            }
+            result = Containment::Intersecting;
+        }
+        result
+    }
+
+    // Tests the corners nearest to and farthest from each plane. Conservative
+    // like any plane by plane test: a box near a corner of the frustum may be
+    // reported as intersecting when it is outside. An infinite box gives NaN
+    // against a zero normal, and counts as intersecting too.
+    #[inline]
+    pub fn test_aabb(&self, aabb: &Aabb<T>) -> Containment {
+        if aabb.is_empty() {
+            return Containment::Outside;
+        }
+        let (center, extents) = (aabb.center(), aabb.extents());
+        let mut result = Containment::Inside;
+        for plane in &self.planes {
+            let distance = plane.signed_distance(center);
+            let radius = extents.dot(plane.normal.map(|x| x.abs()));
+            if distance < -radius {
+                return Containment::Outside;
+            }
+            if distance >= radius {
+                continue;
+            }
+            result = Containment::Intersecting;
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f32::consts::FRAC_PI_2;
+
+    use crate::{mat4, vec3, vec4, Aabb, Containment, Frustum, Sphere};
+
+    use super::plane;
+
+    const EPSILON: f32 = 1e-3;
+
+    // A quarter turn vertically and twice as wide, so that at depth d the
+    // frustum spans |x| <= 2d and |y| <= d, between z = -1 and z = -10.
+    fn check_perspective(frustum: &Frustum<f32>) {
+        let d = 5.0;
+        let boundaries = [
+            vec3::new(-2.0 * d, 0.0, -d), vec3::new(2.0 * d, 0.0, -d),
+            vec3::new(0.0, -d, -d), vec3::new(0.0, d, -d),
+            vec3::new(0.0, 0.0, -1.0), vec3::new(0.0, 0.0, -10.0),
+        ];
+        let inwards = [
+            vec3::new(1.0, 0.0, 0.0), vec3::new(-1.0, 0.0, 0.0),
+            vec3::new(0.0, 1.0, 0.0), vec3::new(0.0, -1.0, 0.0),
+            vec3::new(0.0, 0.0, -1.0), vec3::new(0.0, 0.0, 1.0),
+        ];
+        for (i, (&boundary, &inward)) in boundaries.iter().zip(inwards.iter()).enumerate() {
+            let (inside, outside) = (boundary + inward * EPSILON, boundary - inward * EPSILON);
+            assert!(frustum.contains_point(inside), "plane {i}: {frustum:?}");
+            assert!(!frustum.contains_point(outside), "plane {i}: {frustum:?}");
+            assert!(frustum.planes[i].signed_distance(inside) > 0.0, "plane {i}: {frustum:?}");
+            assert!(frustum.planes[i].signed_distance(outside) < 0.0, "plane {i}: {frustum:?}");
+            assert!((frustum.planes[i].normal.length() - 1.0).abs() <= 1e-6, "plane {i}: {frustum:?}");
+        }
+        // Normalized, so the near plane is 1 away from the eye.
+        assert!((frustum.planes[4].signed_distance(vec3::splat(0.0)) + 1.0).abs() <= 1e-5, "{frustum:?}");
+    }
+
+    #[test]
+    fn from_matrix_zo() {
+        check_perspective(&Frustum::from_matrix_zo(&mat4::perspective_rh_zo(FRAC_PI_2, 2.0, 1.0, 10.0)));
+    }
+
+    #[test]
+    fn from_matrix_no() {
+        check_perspective(&Frustum::from_matrix_no(&mat4::perspective_rh_no(FRAC_PI_2, 2.0, 1.0, 10.0)));
+
+        // The ortho matrix keeps depth in [-1, 1], which is z from 1 to -1.
+        let frustum = Frustum::from_matrix_no(&mat4::ortho(-2.0, 2.0, -1.0, 1.0));
+        for (i, p) in [
+            vec3::new(-2.0, 0.0, 0.0), vec3::new(2.0, 0.0, 0.0),
+            vec3::new(0.0, -1.0, 0.0), vec3::new(0.0, 1.0, 0.0),
+            vec3::new(0.0, 0.0, 1.0), vec3::new(0.0, 0.0, -1.0),
+        ].into_iter().enumerate() {
+            assert!(frustum.contains_point(p * (1.0 - EPSILON)), "plane {i}: {frustum:?}");
+            assert!(!frustum.contains_point(p * (1.0 + EPSILON)), "plane {i}: {frustum:?}");
+            assert!((frustum.planes[i].signed_distance(p)).abs() <= 1e-6, "plane {i}: {frustum:?}");
+        }
+    }
+
+    #[test]
+    fn from_matrix_reversed_zo() {
+        // Swapping near and far maps the near plane to depth 1.
+        check_perspective(&Frustum::from_matrix_reversed_zo(&mat4::perspective_rh_zo(FRAC_PI_2, 2.0, 10.0, 1.0)));
+    }
+
+    #[test]
+    fn infinite_far_plane() {
+        // Reversed depth with the far plane at infinity: depth is 1 / -z.
+        let mut m = mat4::perspective_rh_zo(FRAC_PI_2, 2.0, 1.0, 10.0);
+        m.value[2].z = 0.0;
+        m.value[3].z = 1.0;
+        let frustum = Frustum::from_matrix_reversed_zo(&m);
+        assert_eq!(frustum.planes[5].normal, vec3::splat(0.0));
+        assert_eq!(frustum.planes[5].distance, f32::INFINITY);
+        assert!(frustum.contains_point(vec3::new(0.0, 0.0, -1e30)));
+        assert!(!frustum.contains_point(vec3::new(0.0, 0.0, -1.0 + EPSILON)));
+
+        // The same for GLM's infinite perspective with depth in [-1, 1].
+        let mut m = mat4::perspective_rh_no(FRAC_PI_2, 2.0, 1.0, 10.0);
+        m.value[2].z = -1.0;
+        m.value[3].z = -2.0;
+        let frustum = Frustum::from_matrix_no(&m);
+        assert_eq!(frustum.planes[5].distance, f32::INFINITY);
+        assert!(frustum.contains_point(vec3::new(0.0, 0.0, -1e30)));
+        assert!(!frustum.contains_point(vec3::new(0.0, 0.0, -1.0 + EPSILON)));
+
+        assert_eq!(frustum.test_sphere(&Sphere::new(vec3::new(0.0, 0.0, -1e6), 1.0)), Containment::Inside);
+        let aabb = Aabb::new(vec3::new(-1.0, -1.0, -1e6), vec3::new(1.0, 1.0, -2.0));
+        assert_eq!(frustum.test_aabb(&aabb), Containment::Inside);
+    }
+
+    #[test]
+    fn zero_rows() {
+        // A zero row holds for every point, a zero normal with a negative
+        // distance for none.
+        let all = plane(vec4::new(0.0, 0.0, 0.0, 0.0));
+        assert_eq!(all.distance, f32::INFINITY);
+        assert!(all.signed_distance(vec3::splat(1e30)) >= 0.0);
+        let none = plane(vec4::new(0.0, 0.0, 0.0, -1.0));
+        assert_eq!(none.distance, f32::NEG_INFINITY);
+        assert!(none.signed_distance(vec3::splat(0.0)) < 0.0);
+    }
+
+    #[test]
+    fn test_sphere() {
+        let frustum = Frustum::from_matrix_zo(&mat4::perspective_rh_zo(FRAC_PI_2, 2.0, 1.0, 10.0));
+        let sphere = |z: f32, radius: f32| frustum.test_sphere(&Sphere::new(vec3::new(0.0, 0.0, z), radius));
+        assert_eq!(sphere(-5.0, 1.0), Containment::Inside);
+        assert_eq!(sphere(-5.0, 4.5), Containment::Intersecting);
+        assert_eq!(sphere(-1.0, 0.5), Containment::Intersecting);
+        assert_eq!(sphere(-10.4, 0.5), Containment::Intersecting);
+        assert_eq!(sphere(-10.6, 0.5), Containment::Outside);
+        assert_eq!(sphere(1.0, 1.5), Containment::Outside);
+        assert_eq!(frustum.test_sphere(&Sphere::new(vec3::new(20.0, 0.0, -5.0), 1.0)), Containment::Outside);
+    }
+
+    #[test]
+    fn test_aabb() {
+        let frustum = Frustum::from_matrix_zo(&mat4::perspective_rh_zo(FRAC_PI_2, 2.0, 1.0, 10.0));
+        let aabb = |min: vec3, max: vec3| frustum.test_aabb(&Aabb::new(min, max));
+        assert_eq!(aabb(vec3::new(-1.0, -1.0, -6.0), vec3::new(1.0, 1.0, -4.0)), Containment::Inside);
+        assert_eq!(aabb(vec3::new(-1.0, -1.0, -12.0), vec3::new(1.0, 1.0, -9.0)), Containment::Intersecting);
+        assert_eq!(aabb(vec3::new(9.0, -1.0, -6.0), vec3::new(11.0, 1.0, -4.0)), Containment::Intersecting);
+        assert_eq!(aabb(vec3::new(-1.0, -1.0, -12.0), vec3::new(1.0, 1.0, -11.0)), Containment::Outside);
+        assert_eq!(aabb(vec3::new(13.0, -1.0, -6.0), vec3::new(15.0, 1.0, -4.0)), Containment::Outside);
+        assert_eq!(frustum.test_aabb(&Aabb::EMPTY), Containment::Outside);
+
+        // Infinite boxes give NaN distances, which count as intersecting,
+        // also against the zero normal of an infinite far plane.
+        let everything = Aabb::new(vec3::splat(f32::NEG_INFINITY), vec3::splat(f32::INFINITY));
+        assert_eq!(frustum.test_aabb(&everything), Containment::Intersecting);
+        let mut m = mat4::perspective_rh_zo(FRAC_PI_2, 2.0, 1.0, 10.0);
+        m.value[2].z = 0.0;
+        m.value[3].z = 1.0;
+        let frustum = Frustum::from_matrix_reversed_zo(&m);
+        assert_eq!(frustum.test_aabb(&everything), Containment::Intersecting);
+        let beyond = Aabb::new(vec3::new(-1.0, -1.0, f32::NEG_INFINITY), vec3::new(1.0, 1.0, -2.0));
+        assert_eq!(frustum.test_aabb(&beyond), Containment::Intersecting);
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{mat4, vec2, vec3, vec4, Float};
+
+// The viewport is (x, y, width, height) in window coordinates. The ZO
+// variants map depth to [0, 1] like Direct3D and Vulkan, the NO variants
+// map it to [-1, 1] like OpenGL, which is also the default as in GLM.
+
+#[inline]
+fn clip<T: Float>(obj: vec3<T>, model: mat4<T>, proj: mat4<T>) -> vec4<T> {
+    let clip = proj * (model * vec4::new(obj.x, obj.y, obj.z, T::ONE));
+    clip / clip.w
+}
+
+#[inline]
+fn to_window<T: Float>(ndc: vec3<T>, viewport: vec4<T>) -> vec3<T> {
+    let half = T::from_f64(0.5);
+    vec3::new(
+        (ndc.x * half + half) * viewport.z + viewport.x,
+        (ndc.y * half + half) * viewport.w + viewport.y,
+        ndc.z)
+}
+
+#[inline]
+fn from_window<T: Float>(win: vec3<T>, viewport: vec4<T>) -> vec2<T> {
+    let two = T::ONE + T::ONE;
+    vec2::new(
+        (win.x - viewport.x) / viewport.z * two - T::ONE,
+        (win.y - viewport.y) / viewport.w * two - T::ONE)
+}
+
+#[inline]
+fn from_ndc<T: Float>(ndc: vec3<T>, model: mat4<T>, proj: mat4<T>) -> vec3<T> {
+    let obj = (proj * model).inverse() * vec4::new(ndc.x, ndc.y, ndc.z, T::ONE);
+    vec3::new(obj.x / obj.w, obj.y / obj.w, obj.z / obj.w)
+}
+
+#[inline]
+pub fn project_zo<T: Float>(obj: vec3<T>, model: mat4<T>, proj: mat4<T>, viewport: vec4<T>) -> vec3<T> {
+    let ndc = clip(obj, model, proj);
+    to_window(vec3::new(ndc.x, ndc.y, ndc.z), viewport)
+}
+
+#[inline]
+pub fn project_no<T: Float>(obj: vec3<T>, model: mat4<T>, proj: mat4<T>, viewport: vec4<T>) -> vec3<T> {
+    let half = T::from_f64(0.5);
+    let ndc = clip(obj, model, proj);
+    to_window(vec3::new(ndc.x, ndc.y, ndc.z * half + half), viewport)
+}
+
+#[inline]
+pub fn project<T: Float>(obj: vec3<T>, model: mat4<T>, proj: mat4<T>, viewport: vec4<T>) -> vec3<T> {
+    project_no(obj, model, proj, viewport)
+}
+
+#[inline]
+pub fn un_project_zo<T: Float>(win: vec3<T>, model: mat4<T>, proj: mat4<T>, viewport: vec4<T>) -> vec3<T> {
+    let xy = from_window(win, viewport);
+    from_ndc(vec3::new(xy.x, xy.y, win.z), model, proj)
+}
+
+#[inline]
+pub fn un_project_no<T: Float>(win: vec3<T>, model: mat4<T>, proj: mat4<T>, viewport: vec4<T>) -> vec3<T> {
+    let two = T::ONE + T::ONE;
+    let xy = from_window(win, viewport);
+    from_ndc(vec3::new(xy.x, xy.y, win.z * two - T::ONE), model, proj)
+}
+
+#[inline]
+pub fn un_project<T: Float>(win: vec3<T>, model: mat4<T>, proj: mat4<T>, viewport: vec4<T>) -> vec3<T> {
+    un_project_no(win, model, proj, viewport)
+}
+
+// Restricts drawing to a delta sized region around center, in window
+// coordinates, when applied after the projection. It does not touch depth,
+// so it serves both depth ranges. A non positive delta gives the identity.
+#[inline]
+pub fn pick_matrix<T: Float>(center: vec2<T>, delta: vec2<T>, viewport: vec4<T>) -> mat4<T> {
+    let mut result = mat4::identity();
+    if !(delta.x > T::ZERO && delta.y > T::ZERO) {
+        return result;
+    }
+    let two = T::ONE + T::ONE;
+    result.value[0].x = viewport.z / delta.x;
+    result.value[1].y = viewport.w / delta.y;
+    result.value[3].x = (viewport.z - two * (center.x - viewport.x)) / delta.x;
+    result.value[3].y = (viewport.w - two * (center.y - viewport.y)) / delta.y;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{mat4, pick_matrix, project, project_no, project_zo, un_project, un_project_no, un_project_zo, vec2, vec3, vec4};
+
+    fn close(a: vec3<f64>, b: vec3<f64>, tolerance: f64) -> bool {
+        (a - b).0.iter().all(|d| d.abs() <= tolerance)
+    }
+
+    fn model() -> mat4<f64> {
+        let mut model = mat4::euler_angle_xyz(0.3, -0.5, 1.1) * 2.0;
+        model.value[3] = vec4::new(1.0, -2.0, -15.0, 1.0);
+        model
+    }
+
+    fn points() -> [vec3<f64>; 4] {
+        [vec3::new(0.0, 0.0, 0.0), vec3::new(1.0, 2.0, -3.0), vec3::new(-2.5, 0.5, 1.5), vec3::new(0.1, -1.0, 2.0)]
+    }
+
+    #[test]
+    fn un_project_inverts_project() {
+        let viewport = vec4::new(10.0, 20.0, 640.0, 480.0);
+        let zo = mat4::perspective_rh_zo(1.0, 640.0 / 480.0, 0.1, 100.0);
+        let no = mat4::perspective_rh_no(1.0, 640.0 / 480.0, 0.1, 100.0);
+        for p in points() {
+            let win = project_zo(p, model(), zo, viewport);
+            assert!(win.z > 0.0 && win.z < 1.0);
+            assert!(close(un_project_zo(win, model(), zo, viewport), p, 1e-9), "{p:?}");
+            let win = project_no(p, model(), no, viewport);
+            assert!(win.z > 0.0 && win.z < 1.0);
+            assert!(close(un_project_no(win, model(), no, viewport), p, 1e-9), "{p:?}");
+            assert_eq!(project(p, model(), no, viewport), win);
+            assert_eq!(un_project(win, model(), no, viewport), un_project_no(win, model(), no, viewport));
+        }
+    }
+
+    #[test]
+    fn project_maps_the_view_axis_to_the_viewport_center() {
+        let viewport = vec4::new(10.0, 20.0, 640.0, 480.0);
+        let proj = mat4::perspective_rh_zo(1.0, 640.0 / 480.0, 1.0, 10.0);
+        let near = project_zo(vec3::new(0.0, 0.0, -1.0), mat4::identity(), proj, viewport);
+        let far = project_zo(vec3::new(0.0, 0.0, -10.0), mat4::identity(), proj, viewport);
+        assert!(close(near, vec3::new(330.0, 260.0, 0.0), 1e-12), "{near:?}");
+        assert!(close(far, vec3::new(330.0, 260.0, 1.0), 1e-12), "{far:?}");
+        let proj = mat4::perspective_rh_no(1.0, 640.0 / 480.0, 1.0, 10.0);
+        let near = project_no(vec3::new(0.0, 0.0, -1.0), mat4::identity(), proj, viewport);
+        assert!(close(near, vec3::new(330.0, 260.0, 0.0), 1e-12), "{near:?}");
+    }
+
+    #[test]
+    fn pick_matrix_fills_the_viewport_with_the_region() {
+        let viewport = vec4::new(10.0, 20.0, 640.0, 480.0);
+        let proj = mat4::perspective_rh_no(1.0, 640.0 / 480.0, 0.1, 100.0);
+        let (center, delta) = (vec2::new(200.0, 300.0), vec2::new(8.0, 6.0));
+        let pick = pick_matrix(center, delta, viewport);
+        let corners = [(-0.5, -0.5), (0.5, -0.5), (-0.5, 0.5), (0.5, 0.5), (0.0, 0.0)];
+        for (u, v) in corners {
+            let win = vec3::new(center.x + u * delta.x, center.y + v * delta.y, 0.7);
+            let p = un_project(win, mat4::identity(), proj, viewport);
+            let picked = project(p, mat4::identity(), pick * proj, viewport);
+            let expected = vec3::new(viewport.x + (u + 0.5) * viewport.z, viewport.y + (v + 0.5) * viewport.w, 0.7);
+            assert!(close(picked, expected, 1e-6), "{picked:?} != {expected:?}");
+        }
+        assert_eq!(pick_matrix(center, vec2::new(0.0, 6.0), viewport), mat4::identity());
+        assert_eq!(pick_matrix(center, vec2::new(8.0, -1.0), viewport), mat4::identity());
+    }
+}
@@ -0,0 +1,142 @@
+use core::array;
+
+use crate::{Integer, Vector};
+
+// Applies `f` to the magnitude of `value` and restores the sign. `MIN` is
+// already a power of two and is returned as is, and a magnitude that wraps
+// to `MIN` is correct once negated.
+#[inline]
+pub(crate) fn with_magnitude<T: Integer>(value: T, f: impl FnOnce(T) -> T) -> T {
+    if value >= T::ZERO {
+        f(value)
+    } else if value == T::MIN {
+        value
+    } else {
+        let result = f(T::ZERO - value);
+        if result == T::MIN { result } else { T::ZERO - result }
+    }
+}
+
+// Powers of two that overflow wrap around, to zero for the unsigned types
+// and to `MIN` for the signed ones.
+#[inline]
+pub(crate) fn power_of_two<T: Integer>(exponent: u32) -> T {
+    if exponent >= T::BITS { T::ZERO } else { T::ONE << exponent }
+}
+
+// Zero counts as a power of two, as in GLM, which tests that the magnitude
+// has no bit below its highest one.
+#[inline]
+pub fn is_power_of_two<T: Integer>(value: T) -> bool {
+    if value == T::ZERO {
+        true
+    } else if value < T::ZERO {
+        value == T::MIN || (T::ZERO - value).count_ones() == 1
+    } else {
+        value.count_ones() == 1
+    }
+}
+
+#[inline]
+pub fn next_power_of_two<T: Integer>(value: T) -> T {
+    with_magnitude(value, |value| {
+        if value == T::ZERO || is_power_of_two(value) {
+            value
+        } else {
+            power_of_two(T::BITS - value.leading_zeros())
+        }
+    })
+}
+
+#[inline]
+pub fn prev_power_of_two<T: Integer>(value: T) -> T {
+    with_magnitude(value, |value| {
+        if value == T::ZERO {
+            value
+        } else {
+            power_of_two(T::BITS - 1 - value.leading_zeros())
+        }
+    })
+}
+
+#[inline]
+pub fn is_multiple<T: Integer>(value: T, multiple: T) -> bool {
+    value % multiple == T::ZERO
+}
+
+#[inline]
+pub fn next_multiple<T: Integer>(value: T, multiple: T) -> T {
+    let remainder = value % multiple;
+    if remainder == T::ZERO {
+        value
+    } else if value > T::ZERO {
+        value + (multiple - remainder)
+    } else {
+        value - remainder
+    }
+}
+
+#[inline]
+pub fn prev_multiple<T: Integer>(value: T, multiple: T) -> T {
+    let remainder = value % multiple;
+    if remainder == T::ZERO {
+        value
+    } else if value > T::ZERO {
+        value - remainder
+    } else {
+        value - remainder - multiple
+    }
+}
+
+impl<T: Integer, const N: usize> Vector<T, N> {
+    #[inline]
+    pub fn is_power_of_two(self) -> Vector<bool, N> {
+        self.map(is_power_of_two)
+    }
+
+    #[inline]
+    pub fn next_power_of_two(self) -> Self {
+        self.map(next_power_of_two)
+    }
+
+    #[inline]
+    pub fn prev_power_of_two(self) -> Self {
+        self.map(prev_power_of_two)
+    }
+
+    #[inline]
+    pub fn is_multiple(self, multiple: Self) -> Vector<bool, N> {
+        Vector(array::from_fn(|i| is_multiple(self.0[i], multiple.0[i])))
+    }
+
+    #[inline]
+    pub fn next_multiple(self, multiple: Self) -> Self {
+        Vector(array::from_fn(|i| next_multiple(self.0[i], multiple.0[i])))
+    }
+
+    #[inline]
+    pub fn prev_multiple(self, multiple: Self) -> Self {
+        Vector(array::from_fn(|i| prev_multiple(self.0[i], multiple.0[i])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{is_power_of_two, ivec2};
+
+    #[test]
+    fn powers_of_two() {
+        assert!(is_power_of_two(0u32));
+        assert!(is_power_of_two(0i32));
+        assert!(is_power_of_two(1u8));
+        assert!(is_power_of_two(0x80u8));
+        assert!(!is_power_of_two(3u32));
+        assert!(!is_power_of_two(u32::MAX));
+        assert!(is_power_of_two(-4i32));
+        assert!(!is_power_of_two(-6i32));
+        assert!(is_power_of_two(i32::MIN));
+        assert!(!is_power_of_two(i32::MIN + 1));
+        assert!(!is_power_of_two(i32::MAX));
+        assert_eq!(ivec2::new(0, -3).is_power_of_two().0, [true, false]);
+    }
+}
@@ -0,0 +1,185 @@
+use core::array;
+
+use crate::{half, Float, Integer, Vector};
+
+pub trait FloatUlp: Float {
+    type Distance: Integer;
+
+    fn next_float(self, ulps: u32) -> Self;
+    fn prev_float(self, ulps: u32) -> Self;
+    fn float_distance(self, other: Self) -> Self::Distance;
+}
+
+// Floats are mapped onto integers that are ordered the same way, with both
+// zeros on 0, so stepping and distances become integer arithmetic. Stepping
+// saturates at the infinities and leaves NaN untouched.
+trait OrderedKey: Copy {
+    type Key;
+
+    fn to_key(self) -> Self::Key;
+    fn from_key(key: Self::Key) -> Self;
+    fn step(self, ulps: Self::Key) -> Self;
+}
+
+macro_rules! ulp_impl {
+    ($($t:ty, $bits:ty, $key:ty, $distance:ty;)*) => ($(
+        impl OrderedKey for $t {
+            type Key = $key;
+
+            #[inline]
+            fn to_key(self) -> $key {
+                let bits = self.to_bits();
+                let sign: $bits = 1 << (<$bits>::BITS - 1);
+                if bits & sign == 0 {
+                    bits as $key
+                } else {
+                    -((bits & !sign) as $key)
+                }
+            }
+
+            #[inline]
+            fn from_key(key: $key) -> Self {
+                let sign: $bits = 1 << (<$bits>::BITS - 1);
+                if key >= 0 {
+                    <$t>::from_bits(key as $bits)
+                } else {
+                    <$t>::from_bits((-key) as $bits | sign)
+                }
+            }
+
+            #[inline]
+            fn step(self, ulps: $key) -> Self {
+                if self.is_nan() || ulps == 0 {
+                    return self;
+                }
+                let limit = <$t>::INFINITY.to_key();
+                Self::from_key((self.to_key() + ulps).clamp(-limit, limit))
+            }
+        }
+
+        impl FloatUlp for $t {
+            type Distance = $distance;
+
+            #[inline]
+            fn next_float(self, ulps: u32) -> Self {
+                self.step(ulps as $key)
+            }
+
+            #[inline]
+            fn prev_float(self, ulps: u32) -> Self {
+                self.step(-(ulps as $key))
+            }
+
+            #[inline]
+            fn float_distance(self, other: Self) -> $distance {
+                (self.to_key() - other.to_key()).unsigned_abs() as $distance
+            }
+        }
+    )*)
+}
+
+ulp_impl! {
+    f32, u32, i64, u32;
+    f64, u64, i128, u64;
+    half, u16, i64, u16;
+}
+
+#[inline]
+pub fn next_float<T: FloatUlp>(x: T, ulps: u32) -> T {
+    x.next_float(ulps)
+}
+
+#[inline]
+pub fn prev_float<T: FloatUlp>(x: T, ulps: u32) -> T {
+    x.prev_float(ulps)
+}
+
+#[inline]
+pub fn float_distance<T: FloatUlp>(x: T, y: T) -> T::Distance {
+    x.float_distance(y)
+}
+
+impl<T: FloatUlp, const N: usize> Vector<T, N> {
+    #[inline]
+    pub fn next_float(self, ulps: u32) -> Self {
+        self.map(|x| x.next_float(ulps))
+    }
+
+    #[inline]
+    pub fn prev_float(self, ulps: u32) -> Self {
+        self.map(|x| x.prev_float(ulps))
+    }
+
+    #[inline]
+    pub fn float_distance(self, other: Self) -> Vector<T::Distance, N> {
+        Vector(array::from_fn(|i| self.0[i].float_distance(other.0[i])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{float_distance, half, next_float, prev_float, vec2};
+
+    macro_rules! ulp_tests {
+        ($($name:ident, $t:ty, $bits:ty;)*) => ($(
+            #[test]
+            fn $name() {
+                let sign: $bits = 1 << (<$bits>::BITS - 1);
+                let bits = |x: $t| x.to_bits();
+                let (zero, neg_zero) = (<$t>::from_bits(0), <$t>::from_bits(sign));
+
+                // Both zeros step onto the smallest subnormals.
+                assert_eq!(bits(next_float(zero, 1)), 1);
+                assert_eq!(bits(next_float(neg_zero, 1)), 1);
+                assert_eq!(bits(prev_float(zero, 1)), sign | 1);
+                assert_eq!(bits(prev_float(neg_zero, 1)), sign | 1);
+                assert_eq!(float_distance(zero, neg_zero), 0);
+                assert_eq!(bits(next_float(zero, 0)), 0);
+                assert_eq!(bits(prev_float(neg_zero, 0)), sign);
+
+                // Subnormals are one step apart, across the sign and into the normals.
+                let min = <$t>::MIN_POSITIVE;
+                assert_eq!(bits(prev_float(min, 1)), bits(min) - 1);
+                assert_eq!(bits(next_float(<$t>::from_bits(bits(min) - 1), 1)), bits(min));
+                assert_eq!(float_distance(min, zero), bits(min));
+                assert_eq!(float_distance(<$t>::from_bits(sign | 3), <$t>::from_bits(2)), 5);
+
+                // Stepping saturates at the infinities, however far it goes.
+                assert_eq!(bits(next_float(<$t>::MAX, 1)), bits(<$t>::INFINITY));
+                assert_eq!(bits(next_float(<$t>::INFINITY, 1)), bits(<$t>::INFINITY));
+                assert_eq!(bits(prev_float(<$t>::INFINITY, 1)), bits(<$t>::MAX));
+                assert_eq!(bits(prev_float(<$t>::MIN, 1)), bits(<$t>::NEG_INFINITY));
+                assert_eq!(bits(next_float(<$t>::MAX, u32::MAX)), bits(<$t>::INFINITY));
+                assert_eq!(bits(prev_float(<$t>::MIN, 0x8000_0000)), bits(<$t>::NEG_INFINITY));
+                assert_eq!(float_distance(<$t>::INFINITY, <$t>::MAX), 1);
+
+                // Steps of every size are exact and never wrap around.
+                for ulps in [u32::MAX, 0x8000_0000, 0x7fff_ffff] {
+                    let (up, down) = (next_float(zero, ulps), prev_float(zero, ulps));
+                    assert!(up > zero && down < zero);
+                    let far = (float_distance(<$t>::INFINITY, zero) as u64).min(ulps as u64);
+                    assert_eq!(float_distance(up, zero) as u64, far);
+                    assert_eq!(float_distance(zero, down) as u64, far);
+                }
+
+                // NaN is left untouched.
+                assert!(next_float(<$t>::NAN, 1).is_nan());
+                assert!(prev_float(<$t>::NAN, u32::MAX).is_nan());
+                assert_eq!(float_distance(<$t>::NAN, <$t>::NAN), 0);
+            }
+        )*)
+    }
+
+    ulp_tests! {
+        f32_steps, f32, u32;
+        f64_steps, f64, u64;
+        half_steps, half, u16;
+    }
+
+    #[test]
+    fn vectors_step_per_lane() {
+        let v = vec2::new(1.0f32, -0.0);
+        assert_eq!(v.next_float(1), vec2::new(f32::from_bits(0x3f80_0001), f32::from_bits(1)));
+        assert_eq!(v.prev_float(2).float_distance(v), vec2::new(2, 2));
+    }
+}
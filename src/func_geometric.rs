@@ -1,31 +1,81 @@
-use crate::{vec2, vec3, vec4};
-
-macro_rules! dot_impl {
-    ($($t:ty)*) => ($(
-        impl vec2<$t> {
-            #[inline]
-            pub const fn dot(self, other: Self) -> $t {
-                let tmp = Self::new(self.x * other.x, self.y * other.y);
-                tmp.x + tmp.y
-            }
-        }
+use core::{array, ops::Add};
 
-        impl vec3<$t> {
-            #[inline]
-            pub const fn dot(self, other: Self) -> $t {
-                let tmp = Self::new(self.x * other.x, self.y * other.y, self.z * other.z);
-                tmp.x + tmp.y + tmp.z
-            }
-        }
+use crate::{vec3, Float, Number, Vector};
 
-        impl vec4<$t> {
-            #[inline]
-            pub const fn dot(self, other: Self) -> $t {
-                let tmp = Self::new(self.x * other.x, self.y * other.y, self.z * other.z, self.w * other.w);
-                (tmp.x + tmp.y) + (tmp.z + tmp.w)
-            }
+// Sums as a tree split at the largest power of two, which is GLM's order:
+// (a + b) + c for three terms and (a + b) + (c + d) for four. An empty slice
+// sums to zero.
+#[inline]
+pub(crate) fn tree_sum<T: Copy + Add<Output = T>>(values: &[T], zero: T) -> T {
+    match values.len() {
+        0 => zero,
+        1 => values[0],
+        n => {
+            let half = n.next_power_of_two() / 2;
+            tree_sum(&values[..half], zero) + tree_sum(&values[half..], zero)
         }
-    )*)
+    }
+}
+
+impl<T: Number, const N: usize> Vector<T, N> {
+    #[inline]
+    pub fn dot(self, other: Self) -> T {
+        tree_sum(&array::from_fn::<T, N, _>(|i| self.0[i] * other.0[i]), T::ZERO)
+    }
 }
 
-dot_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
+impl<T: Number> vec3<T> {
+    #[inline]
+    pub fn cross(self, other: Self) -> Self {
+        Self::new(
+            self.y * other.z - other.y * self.z,
+            self.z * other.x - other.z * self.x,
+            self.x * other.y - other.x * self.y)
+    }
+}
+
+impl<T: Float, const N: usize> Vector<T, N> {
+    #[inline]
+    pub fn length(self) -> T {
+        self.dot(self).sqrt()
+    }
+
+    #[inline]
+    pub fn distance(self, other: Self) -> T {
+        (other - self).length()
+    }
+
+    #[inline]
+    pub fn normalize(self) -> Self {
+        self * (T::ONE / self.length())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{mat3, mat4, vec2, vec3, vec4, Vector};
+
+    // The products 1e8, 1, -1e8, 1 sum to 0 pairwise in f32 but to 1 from
+    // left to right, as 1e8 + 1 rounds to 1e8.
+    #[test]
+    fn dot_sums_like_glm() {
+        let (a, b) = (vec4::new(1e8f32, 1.0, -1e8, 1.0), vec4::ONE);
+        assert_eq!(a.dot(b).to_bits(), 0.0f32.to_bits());
+        assert_eq!(vec4::new(0.1f32, 0.7, 1e-3, 3.3).dot(vec4::new(0.3, 0.9, 7.0, 1.1)).to_bits(), ((0.1f32 * 0.3 + 0.7 * 0.9) + (1e-3 * 7.0 + 3.3 * 1.1)).to_bits());
+        assert_eq!(vec3::new(1e8f32, 1.0, 1.0).dot(vec3::ONE), (1e8f32 + 1.0) + 1.0);
+        assert_eq!(vec2::new(0.1f64, 0.2).dot(vec2::new(0.3, 0.4)), 0.1 * 0.3 + 0.2 * 0.4);
+        assert_eq!(vec4::new(3, -2, 7, 1).dot(vec4::new(1, 4, -1, 5)), -7);
+        assert_eq!(Vector::<f32, 0>::ZERO.dot(Vector::ZERO), 0.0);
+    }
+
+    #[test]
+    fn products_sum_like_glm() {
+        let m = mat4::new(vec4::new(1e8f32, 0.0, 0.0, 0.0), vec4::X, vec4::new(-1e8, 0.0, 0.0, 0.0), vec4::X);
+        assert_eq!((m * vec4::ONE).x, 0.0);
+        assert_eq!((vec4::new(1e8f32, 1.0, -1e8, 1.0) * mat4::identity()).x, 1e8);
+        // Matrix products accumulate from left to right.
+        assert_eq!((m * mat4::identity() * mat4::new(vec4::ONE, vec4::ZERO, vec4::ZERO, vec4::ZERO))[0].x, 1.0);
+        let m = mat3::new(vec3::new(1e8f32, 0.0, 0.0), vec3::X, vec3::X);
+        assert_eq!((m * vec3::ONE).x, (1e8f32 + 1.0) + 1.0);
+    }
+}
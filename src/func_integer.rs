@@ -0,0 +1,240 @@
+use core::{array, ffi::{c_int, c_uint}};
+
+use crate::{Integer, Vector};
+
+// GLSL leaves fields outside the value undefined; they panic here.
+#[inline]
+fn assert_field<T: Integer>(offset: u32, bits: u32) {
+    assert!(offset.checked_add(bits).is_some_and(|end| end <= T::BITS), "bit field out of range");
+}
+
+#[inline]
+pub fn bitfield_extract<T: Integer>(value: T, offset: u32, bits: u32) -> T {
+    assert_field::<T>(offset, bits);
+    if bits == 0 {
+        return T::ZERO;
+    }
+    // The right shift is arithmetic for signed types, which sign-extends the field.
+    (value << (T::BITS - offset - bits)) >> (T::BITS - bits)
+}
+
+#[inline]
+pub fn bitfield_insert<T: Integer>(base: T, insert: T, offset: u32, bits: u32) -> T {
+    assert_field::<T>(offset, bits);
+    if bits == 0 {
+        return base;
+    }
+    let mask = if bits == T::BITS { !T::ZERO } else { !(!T::ZERO << bits) } << offset;
+    (base & !mask) | ((insert << offset) & mask)
+}
+
+#[inline]
+pub fn bitfield_reverse<T: Integer>(value: T) -> T {
+    value.reverse_bits()
+}
+
+#[inline]
+pub fn bit_count<T: Integer>(value: T) -> c_int {
+    value.count_ones() as c_int
+}
+
+#[inline]
+pub fn find_lsb<T: Integer>(value: T) -> c_int {
+    if value == T::ZERO {
+        -1
+    } else {
+        value.trailing_zeros() as c_int
+    }
+}
+
+#[inline]
+pub fn find_msb<T: Integer>(value: T) -> c_int {
+    // For negative values this is the most significant zero bit, so both 0 and -1 give -1.
+    let value = if value < T::ZERO { !value } else { value };
+    if value == T::ZERO {
+        -1
+    } else {
+        (T::BITS - 1 - value.leading_zeros()) as c_int
+    }
+}
+
+#[inline]
+pub fn uadd_carry(x: c_uint, y: c_uint) -> (c_uint, c_uint) {
+    let (sum, carry) = x.overflowing_add(y);
+    (sum, carry as c_uint)
+}
+
+#[inline]
+pub fn usub_borrow(x: c_uint, y: c_uint) -> (c_uint, c_uint) {
+    let (difference, borrow) = x.overflowing_sub(y);
+    (difference, borrow as c_uint)
+}
+
+#[inline]
+pub fn umul_extended(x: c_uint, y: c_uint) -> (c_uint, c_uint) {
+    let product = x as u64 * y as u64;
+    ((product >> 32) as c_uint, product as c_uint)
+}
+
+#[inline]
+pub fn imul_extended(x: c_int, y: c_int) -> (c_int, c_int) {
+    let product = x as i64 * y as i64;
+    ((product >> 32) as c_int, product as c_int)
+}
+
+impl<T: Integer, const N: usize> Vector<T, N> {
+    #[inline]
+    pub fn bitfield_extract(self, offset: u32, bits: u32) -> Self {
+        self.map(|x| bitfield_extract(x, offset, bits))
+    }
+
+    #[inline]
+    pub fn bitfield_insert(self, insert: Self, offset: u32, bits: u32) -> Self {
+        Vector(array::from_fn(|i| bitfield_insert(self.0[i], insert.0[i], offset, bits)))
+    }
+
+    #[inline]
+    pub fn bitfield_reverse(self) -> Self {
+        self.map(bitfield_reverse)
+    }
+
+    #[inline]
+    pub fn bit_count(self) -> Vector<c_int, N> {
+        self.map(bit_count)
+    }
+
+    #[inline]
+    pub fn find_lsb(self) -> Vector<c_int, N> {
+        self.map(find_lsb)
+    }
+
+    #[inline]
+    pub fn find_msb(self) -> Vector<c_int, N> {
+        self.map(find_msb)
+    }
+}
+
+macro_rules! extended_impl {
+    ($t:ty, $($method:ident)*) => {
+        impl<const N: usize> Vector<$t, N> {
+            $(
+                #[inline]
+                pub fn $method(self, other: Self) -> (Self, Self) {
+                    let mut first = self;
+                    let mut second = self;
+                    for i in 0..N {
+                        (first.0[i], second.0[i]) = $method(self.0[i], other.0[i]);
+                    }
+                    (first, second)
+                }
+            )*
+        }
+    }
+}
+
+extended_impl! { c_uint, uadd_carry usub_borrow umul_extended }
+extended_impl! { c_int, imul_extended }
+
+#[cfg(test)]
+mod tests {
+    use crate::{bit_count, bitfield_extract, bitfield_insert, bitfield_reverse, find_lsb, find_msb, imul_extended, ivec2, uadd_carry, umul_extended, usub_borrow, uvec2};
+
+    #[test]
+    fn extract() {
+        assert_eq!(bitfield_extract(0x1234_5678u32, 4, 8), 0x67);
+        assert_eq!(bitfield_extract(0x1234_5678u32, 0, 32), 0x1234_5678);
+        assert_eq!(bitfield_extract(0x1234_5678u32, 28, 4), 0x1);
+        assert_eq!(bitfield_extract(u32::MAX, 32, 0), 0);
+        assert_eq!(bitfield_extract(u32::MAX, 7, 0), 0);
+        // Signed fields are sign-extended from their top bit.
+        assert_eq!(bitfield_extract(0b1000i32, 0, 4), -8);
+        assert_eq!(bitfield_extract(0b0111i32, 0, 4), 7);
+        assert_eq!(bitfield_extract(-1i32, 5, 3), -1);
+        assert_eq!(bitfield_extract(i32::MIN, 0, 32), i32::MIN);
+        assert_eq!(bitfield_extract(i32::MIN, 31, 1), -1);
+        assert_eq!(bitfield_extract(0x80u8, 4, 4), 0x8);
+        assert_eq!(bitfield_extract(0x80u8 as i8, 4, 4), -8);
+        assert_eq!(ivec2::new(0x30, -0x30).bitfield_extract(4, 3), ivec2::new(3, -3));
+    }
+
+    #[test]
+    fn insert() {
+        assert_eq!(bitfield_insert(0xffff_ffffu32, 0, 8, 8), 0xffff_00ff);
+        assert_eq!(bitfield_insert(0u32, 0x1ff, 8, 8), 0xff00);
+        assert_eq!(bitfield_insert(0x1234u32, 0xabcd, 0, 32), 0xabcd);
+        assert_eq!(bitfield_insert(0x1234u32, 0xabcd, 32, 0), 0x1234);
+        assert_eq!(bitfield_insert(0x1234u32, 0xabcd, 4, 0), 0x1234);
+        assert_eq!(bitfield_insert(0i32, -1, 28, 4), -0x1000_0000);
+        assert_eq!(bitfield_insert(-1i32, 0, 31, 1), i32::MAX);
+        assert_eq!(uvec2::new(0, 0xff).bitfield_insert(uvec2::new(0xf, 0), 4, 4), uvec2::new(0xf0, 0x0f));
+    }
+
+    #[test]
+    #[should_panic]
+    fn extract_past_the_end_panics() {
+        bitfield_extract(1u32, 30, 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_with_an_overflowing_range_panics() {
+        bitfield_insert(1u32, 1, u32::MAX, 2);
+    }
+
+    #[test]
+    fn reverse_and_count() {
+        assert_eq!(bitfield_reverse(1u32), 0x8000_0000);
+        assert_eq!(bitfield_reverse(0x0000_00f1u32), 0x8f00_0000);
+        assert_eq!(bitfield_reverse(1i32), i32::MIN);
+        assert_eq!(bitfield_reverse(0u8), 0);
+        assert_eq!(bit_count(0u32), 0);
+        assert_eq!(bit_count(u32::MAX), 32);
+        assert_eq!(bit_count(-1i32), 32);
+        assert_eq!(bit_count(i32::MIN), 1);
+        assert_eq!(bit_count(0xf0f0u16), 8);
+        assert_eq!(ivec2::new(-1, 5).bit_count(), ivec2::new(32, 2));
+    }
+
+    #[test]
+    fn find_bits() {
+        assert_eq!(find_lsb(0u32), -1);
+        assert_eq!(find_lsb(1u32), 0);
+        assert_eq!(find_lsb(0x8000_0000u32), 31);
+        assert_eq!(find_lsb(-1i32), 0);
+        assert_eq!(find_lsb(i32::MIN), 31);
+        assert_eq!(find_lsb(0x50u8), 4);
+
+        assert_eq!(find_msb(0u32), -1);
+        assert_eq!(find_msb(1u32), 0);
+        assert_eq!(find_msb(u32::MAX), 31);
+        assert_eq!(find_msb(i32::MAX), 30);
+        // Negative values report their most significant zero bit.
+        assert_eq!(find_msb(0i32), -1);
+        assert_eq!(find_msb(-1i32), -1);
+        assert_eq!(find_msb(-2i32), 0);
+        assert_eq!(find_msb(i32::MIN), 30);
+        assert_eq!(ivec2::new(-8, 8).find_msb(), ivec2::new(2, 3));
+    }
+
+    #[test]
+    fn extended_arithmetic() {
+        assert_eq!(uadd_carry(u32::MAX, 1), (0, 1));
+        assert_eq!(uadd_carry(1, 2), (3, 0));
+        assert_eq!(usub_borrow(0, 1), (u32::MAX, 1));
+        assert_eq!(usub_borrow(3, 2), (1, 0));
+
+        assert_eq!(umul_extended(u32::MAX, u32::MAX), (0xffff_fffe, 1));
+        assert_eq!(umul_extended(0x1_0000, 0x1_0000), (1, 0));
+        assert_eq!(umul_extended(0, u32::MAX), (0, 0));
+        assert_eq!(imul_extended(-1, 1), (-1, -1));
+        assert_eq!(imul_extended(i32::MIN, i32::MIN), (0x4000_0000, 0));
+        assert_eq!(imul_extended(i32::MIN, i32::MAX), (-0x4000_0000, i32::MIN));
+        assert_eq!(imul_extended(i32::MAX, i32::MAX), (0x3fff_ffff, 1));
+        assert_eq!(imul_extended(-3, 0x7000_0000), (-2, -0x5000_0000));
+
+        let (high, low) = uvec2::new(u32::MAX, 2).umul_extended(uvec2::new(2, 3));
+        assert_eq!((high, low), (uvec2::new(1, 0), uvec2::new(0xffff_fffe, 6)));
+        let (sum, carry) = uvec2::new(u32::MAX, 1).uadd_carry(uvec2::new(1, 1));
+        assert_eq!((sum, carry), (uvec2::new(0, 2), uvec2::new(1, 0)));
+    }
+}
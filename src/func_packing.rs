@@ -0,0 +1,209 @@
+use core::ffi::c_uint;
+
+use crate::{half, uvec2, vec2, vec4, Float};
+
+#[inline]
+pub const fn pack_half_1x16(v: f32) -> u16 {
+    half::from_f32(v).to_bits()
+}
+
+#[inline]
+pub const fn unpack_half_1x16(p: u16) -> f32 {
+    half::from_bits(p).to_f32()
+}
+
+#[inline]
+pub const fn pack_half_2x16(v: vec2) -> u32 {
+    (pack_half_1x16(v.0[0]) as u32) | ((pack_half_1x16(v.0[1]) as u32) << 16)
+}
+
+#[inline]
+pub const fn unpack_half_2x16(p: u32) -> vec2 {
+    vec2::new(unpack_half_1x16(p as u16), unpack_half_1x16((p >> 16) as u16))
+}
+
+#[inline]
+pub const fn pack_half_4x16(v: vec4) -> u64 {
+    (pack_half_1x16(v.0[0]) as u64)
+        | ((pack_half_1x16(v.0[1]) as u64) << 16)
+        | ((pack_half_1x16(v.0[2]) as u64) << 32)
+        | ((pack_half_1x16(v.0[3]) as u64) << 48)
+}
+
+#[inline]
+pub const fn unpack_half_4x16(p: u64) -> vec4 {
+    vec4::new(
+        unpack_half_1x16(p as u16),
+        unpack_half_1x16((p >> 16) as u16),
+        unpack_half_1x16((p >> 32) as u16),
+        unpack_half_1x16((p >> 48) as u16))
+}
+
+// GLSL: round(clamp(c, 0, +1) * max) and round(clamp(c, -1, +1) * max).
+#[inline]
+pub(crate) fn unorm_to_bits(c: f32, max: f32) -> f32 {
+    Float::round(c.clamp(0.0, 1.0) * max)
+}
+
+#[inline]
+pub(crate) fn snorm_to_bits(c: f32, max: f32) -> f32 {
+    Float::round(c.clamp(-1.0, 1.0) * max)
+}
+
+#[inline]
+pub(crate) fn bits_to_snorm(f: f32, max: f32) -> f32 {
+    (f / max).clamp(-1.0, 1.0)
+}
+
+#[inline]
+pub fn pack_unorm_2x16(v: vec2) -> u32 {
+    (unorm_to_bits(v.x, 65535.0) as u16 as u32)
+        | ((unorm_to_bits(v.y, 65535.0) as u16 as u32) << 16)
+}
+
+#[inline]
+pub fn unpack_unorm_2x16(p: u32) -> vec2 {
+    vec2::new(
+        p as u16 as f32 / 65535.0,
+        (p >> 16) as u16 as f32 / 65535.0)
+}
+
+#[inline]
+pub fn pack_snorm_2x16(v: vec2) -> u32 {
+    (snorm_to_bits(v.x, 32767.0) as i16 as u16 as u32)
+        | ((snorm_to_bits(v.y, 32767.0) as i16 as u16 as u32) << 16)
+}
+
+#[inline]
+pub fn unpack_snorm_2x16(p: u32) -> vec2 {
+    vec2::new(
+        bits_to_snorm(p as i16 as f32, 32767.0),
+        bits_to_snorm((p >> 16) as i16 as f32, 32767.0))
+}
+
+#[inline]
+pub fn pack_unorm_4x8(v: vec4) -> u32 {
+    (unorm_to_bits(v.x, 255.0) as u8 as u32)
+        | ((unorm_to_bits(v.y, 255.0) as u8 as u32) << 8)
+        | ((unorm_to_bits(v.z, 255.0) as u8 as u32) << 16)
+        | ((unorm_to_bits(v.w, 255.0) as u8 as u32) << 24)
+}
+
+#[inline]
+pub fn unpack_unorm_4x8(p: u32) -> vec4 {
+    vec4::new(
+        p as u8 as f32 / 255.0,
+        (p >> 8) as u8 as f32 / 255.0,
+        (p >> 16) as u8 as f32 / 255.0,
+        (p >> 24) as u8 as f32 / 255.0)
+}
+
+#[inline]
+pub fn pack_snorm_4x8(v: vec4) -> u32 {
+    (snorm_to_bits(v.x, 127.0) as i8 as u8 as u32)
+        | ((snorm_to_bits(v.y, 127.0) as i8 as u8 as u32) << 8)
+        | ((snorm_to_bits(v.z, 127.0) as i8 as u8 as u32) << 16)
+        | ((snorm_to_bits(v.w, 127.0) as i8 as u8 as u32) << 24)
+}
+
+#[inline]
+pub fn unpack_snorm_4x8(p: u32) -> vec4 {
+    vec4::new(
+        bits_to_snorm(p as i8 as f32, 127.0),
+        bits_to_snorm((p >> 8) as i8 as f32, 127.0),
+        bits_to_snorm((p >> 16) as i8 as f32, 127.0),
+        bits_to_snorm((p >> 24) as i8 as f32, 127.0))
+}
+
+#[inline]
+pub const fn pack_double_2x32(v: uvec2) -> f64 {
+    f64::from_bits((v.0[0] as u64) | ((v.0[1] as u64) << 32))
+}
+
+#[inline]
+pub const fn unpack_double_2x32(v: f64) -> uvec2 {
+    let bits = v.to_bits();
+    uvec2::new(bits as c_uint, (bits >> 32) as c_uint)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        pack_double_2x32, pack_half_1x16, pack_snorm_2x16, pack_snorm_4x8, pack_unorm_2x16, pack_unorm_4x8,
+        unpack_double_2x32, unpack_half_1x16, unpack_snorm_2x16, unpack_snorm_4x8, unpack_unorm_2x16,
+        unpack_unorm_4x8, uvec2, vec2, vec4,
+    };
+
+    #[test]
+    fn half_round_trips() {
+        for p in 0..=u16::MAX {
+            let value = unpack_half_1x16(p);
+            if value.is_nan() {
+                assert!(unpack_half_1x16(pack_half_1x16(value)).is_nan(), "{p:#x}");
+            } else {
+                assert_eq!(pack_half_1x16(value), p);
+            }
+        }
+    }
+
+    #[test]
+    fn unorm_round_trips() {
+        for p in 0..=u16::MAX as u32 {
+            let packed = p | (p << 16);
+            assert_eq!(pack_unorm_2x16(unpack_unorm_2x16(packed)), packed);
+        }
+        for p in 0..=u8::MAX as u32 {
+            let packed = p * 0x0101_0101;
+            assert_eq!(pack_unorm_4x8(unpack_unorm_4x8(packed)), packed);
+        }
+    }
+
+    #[test]
+    fn snorm_round_trips() {
+        // The most negative value unpacks to -1 like the one above it, and
+        // packs back to that.
+        for p in i16::MIN..=i16::MAX {
+            let expected = p.max(-i16::MAX) as u16 as u32;
+            let packed = p as u16 as u32;
+            assert_eq!(pack_snorm_2x16(unpack_snorm_2x16(packed | (packed << 16))), expected | (expected << 16));
+        }
+        for p in i8::MIN..=i8::MAX {
+            let expected = p.max(-i8::MAX) as u8 as u32 * 0x0101_0101;
+            let packed = p as u8 as u32 * 0x0101_0101;
+            assert_eq!(pack_snorm_4x8(unpack_snorm_4x8(packed)), expected);
+        }
+    }
+
+    #[test]
+    fn norm_error_is_half_a_step() {
+        for i in 0..=10000 {
+            let x = i as f32 / 10000.0;
+            let unorm = unpack_unorm_2x16(pack_unorm_2x16(vec2::splat(x)));
+            assert!((unorm.x - x).abs() <= 0.5 / 65535.0 + f32::EPSILON, "{x}");
+            let unorm = unpack_unorm_4x8(pack_unorm_4x8(vec4::splat(x)));
+            assert!((unorm.x - x).abs() <= 0.5 / 255.0 + f32::EPSILON, "{x}");
+
+            let x = x * 2.0 - 1.0;
+            let snorm = unpack_snorm_2x16(pack_snorm_2x16(vec2::splat(x)));
+            assert!((snorm.x - x).abs() <= 0.5 / 32767.0 + f32::EPSILON, "{x}");
+            let snorm = unpack_snorm_4x8(pack_snorm_4x8(vec4::splat(x)));
+            assert!((snorm.x - x).abs() <= 0.5 / 127.0 + f32::EPSILON, "{x}");
+        }
+    }
+
+    #[test]
+    fn norm_clamps() {
+        assert_eq!(pack_unorm_2x16(vec2::new(-1.0, 2.0)), 0xffff_0000);
+        assert_eq!(pack_snorm_2x16(vec2::new(-2.0, 2.0)), 0x7fff_8001);
+        assert_eq!(pack_unorm_4x8(vec4::new(-1.0, 2.0, f32::NEG_INFINITY, f32::INFINITY)), 0xff00_ff00);
+        assert_eq!(pack_snorm_4x8(vec4::new(-2.0, 2.0, 0.0, -0.0)), 0x0000_7f81);
+    }
+
+    #[test]
+    fn double_splits_into_low_and_high_words() {
+        assert_eq!(unpack_double_2x32(1.0), uvec2::new(0, 0x3ff0_0000));
+        assert_eq!(pack_double_2x32(uvec2::new(0, 0x3ff0_0000)), 1.0);
+        let words = uvec2::new(0xdead_beef, 0x7ff8_0001);
+        assert_eq!(unpack_double_2x32(pack_double_2x32(words)), words);
+    }
+}
@@ -0,0 +1,273 @@
+use core::array;
+
+use crate::{Cast, Integer, Vector};
+
+#[inline]
+fn mask_bits<T: Integer>(bits: u32) -> T {
+    if bits >= T::BITS {
+        !T::ZERO
+    } else {
+        !(!T::ZERO << bits)
+    }
+}
+
+#[inline]
+pub fn mask<T: Integer + Cast<u32>>(bits: T) -> T {
+    mask_bits(bits.cast())
+}
+
+#[inline]
+pub fn bitfield_rotate_left<T: Integer>(value: T, shift: u32) -> T {
+    value.rotate_left(shift)
+}
+
+#[inline]
+pub fn bitfield_rotate_right<T: Integer>(value: T, shift: u32) -> T {
+    value.rotate_right(shift)
+}
+
+#[inline]
+pub fn bitfield_fill_one<T: Integer>(value: T, first_bit: u32, bit_count: u32) -> T {
+    value | (mask_bits::<T>(bit_count) << first_bit)
+}
+
+#[inline]
+pub fn bitfield_fill_zero<T: Integer>(value: T, first_bit: u32, bit_count: u32) -> T {
+    value & !(mask_bits::<T>(bit_count) << first_bit)
+}
+
+impl<T: Integer + Cast<u32>, const N: usize> Vector<T, N> {
+    #[inline]
+    pub fn mask(self) -> Self {
+        self.map(mask)
+    }
+}
+
+impl<T: Integer, const N: usize> Vector<T, N> {
+    #[inline]
+    pub fn bitfield_rotate_left(self, shift: u32) -> Self {
+        self.map(|x| bitfield_rotate_left(x, shift))
+    }
+
+    #[inline]
+    pub fn bitfield_rotate_right(self, shift: u32) -> Self {
+        self.map(|x| bitfield_rotate_right(x, shift))
+    }
+
+    #[inline]
+    pub fn bitfield_fill_one(self, first_bit: u32, bit_count: u32) -> Self {
+        self.map(|x| bitfield_fill_one(x, first_bit, bit_count))
+    }
+
+    #[inline]
+    pub fn bitfield_fill_zero(self, first_bit: u32, bit_count: u32) -> Self {
+        self.map(|x| bitfield_fill_zero(x, first_bit, bit_count))
+    }
+}
+
+// Spread the low bits of `x` so that bit k lands on bit k * 2, k * 3 or
+// k * 4, and the inverse.
+
+#[inline]
+const fn spread_2(x: u64) -> u64 {
+    let x = x & 0x0000_0000_ffff_ffff;
+    let x = (x | (x << 16)) & 0x0000_ffff_0000_ffff;
+    let x = (x | (x << 8)) & 0x00ff_00ff_00ff_00ff;
+    let x = (x | (x << 4)) & 0x0f0f_0f0f_0f0f_0f0f;
+    let x = (x | (x << 2)) & 0x3333_3333_3333_3333;
+    (x | (x << 1)) & 0x5555_5555_5555_5555
+}
+
+#[inline]
+const fn compact_2(x: u64) -> u64 {
+    let x = x & 0x5555_5555_5555_5555;
+    let x = (x | (x >> 1)) & 0x3333_3333_3333_3333;
+    let x = (x | (x >> 2)) & 0x0f0f_0f0f_0f0f_0f0f;
+    let x = (x | (x >> 4)) & 0x00ff_00ff_00ff_00ff;
+    let x = (x | (x >> 8)) & 0x0000_ffff_0000_ffff;
+    (x | (x >> 16)) & 0x0000_0000_ffff_ffff
+}
+
+#[inline]
+const fn spread_3(x: u64) -> u64 {
+    let x = x & 0x0000_0000_001f_ffff;
+    let x = (x | (x << 32)) & 0x001f_0000_0000_ffff;
+    let x = (x | (x << 16)) & 0x001f_0000_ff00_00ff;
+    let x = (x | (x << 8)) & 0x100f_00f0_0f00_f00f;
+    let x = (x | (x << 4)) & 0x10c3_0c30_c30c_30c3;
+    (x | (x << 2)) & 0x1249_2492_4924_9249
+}
+
+#[inline]
+const fn compact_3(x: u64) -> u64 {
+    let x = x & 0x1249_2492_4924_9249;
+    let x = (x | (x >> 2)) & 0x10c3_0c30_c30c_30c3;
+    let x = (x | (x >> 4)) & 0x100f_00f0_0f00_f00f;
+    let x = (x | (x >> 8)) & 0x001f_0000_ff00_00ff;
+    let x = (x | (x >> 16)) & 0x001f_0000_0000_ffff;
+    (x | (x >> 32)) & 0x0000_0000_001f_ffff
+}
+
+#[inline]
+const fn spread_4(x: u64) -> u64 {
+    let x = x & 0x0000_0000_0000_ffff;
+    let x = (x | (x << 24)) & 0x0000_00ff_0000_00ff;
+    let x = (x | (x << 12)) & 0x000f_000f_000f_000f;
+    let x = (x | (x << 6)) & 0x0303_0303_0303_0303;
+    (x | (x << 3)) & 0x1111_1111_1111_1111
+}
+
+#[inline]
+const fn compact_4(x: u64) -> u64 {
+    let x = x & 0x1111_1111_1111_1111;
+    let x = (x | (x >> 3)) & 0x0303_0303_0303_0303;
+    let x = (x | (x >> 6)) & 0x000f_000f_000f_000f;
+    let x = (x | (x >> 12)) & 0x0000_00ff_0000_00ff;
+    (x | (x >> 24)) & 0x0000_0000_0000_ffff
+}
+
+pub trait BitfieldInterleave<const N: usize>: Integer {
+    type Interleaved: Integer;
+
+    fn interleave(v: [Self; N]) -> Self::Interleaved;
+    fn deinterleave(p: Self::Interleaved) -> [Self; N];
+}
+
+macro_rules! interleave_impl {
+    ($($t:ty, $n:literal, $output:ty, $spread:ident, $compact:ident;)*) => ($(
+        impl BitfieldInterleave<$n> for $t {
+            type Interleaved = $output;
+
+            #[inline]
+            fn interleave(v: [Self; $n]) -> $output {
+                let mut result = 0;
+                for (i, x) in v.into_iter().enumerate() {
+                    result |= $spread(x as u64) << i;
+                }
+                result as $output
+            }
+
+            #[inline]
+            fn deinterleave(p: $output) -> [Self; $n] {
+                array::from_fn(|i| $compact(p as u64 >> i) as $t)
+            }
+        }
+    )*)
+}
+
+// Three u32 components only keep their low 21 bits.
+interleave_impl! {
+    u8, 2, u16, spread_2, compact_2;
+    u8, 3, u32, spread_3, compact_3;
+    u8, 4, u32, spread_4, compact_4;
+    u16, 2, u32, spread_2, compact_2;
+    u16, 3, u64, spread_3, compact_3;
+    u16, 4, u64, spread_4, compact_4;
+    u32, 2, u64, spread_2, compact_2;
+    u32, 3, u64, spread_3, compact_3;
+}
+
+#[inline]
+pub fn bitfield_interleave<T: BitfieldInterleave<N>, const N: usize>(v: Vector<T, N>) -> T::Interleaved {
+    T::interleave(v.0)
+}
+
+#[inline]
+pub fn bitfield_deinterleave<T: BitfieldInterleave<N>, const N: usize>(p: T::Interleaved) -> Vector<T, N> {
+    Vector(T::deinterleave(p))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        bitfield_deinterleave, bitfield_fill_one, bitfield_fill_zero, bitfield_interleave, bitfield_rotate_left, bitfield_rotate_right,
+        mask, uvec2, BitfieldInterleave, Vector,
+    };
+
+    // Bit b of component i lands on bit b * N + i.
+    fn naive_interleave<const N: usize>(v: [u64; N], bits: u32) -> u64 {
+        let mut result = 0;
+        for (i, x) in v.into_iter().enumerate() {
+            for b in 0..bits {
+                result |= (x >> b & 1) << (b as usize * N + i);
+            }
+        }
+        result
+    }
+
+    fn check<T, const N: usize>(seed: &mut u64)
+    where
+        T: BitfieldInterleave<N> + TryFrom<u64> + Into<u64>,
+        T::Interleaved: Into<u64>,
+    {
+        for _ in 0..1000 {
+            let v: [T; N] = core::array::from_fn(|_| {
+                *seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                T::try_from(*seed >> (64 - T::BITS)).ok().unwrap()
+            });
+            // Components keep as many low bits as fit in 64 bits.
+            let kept = (64 / N as u32).min(T::BITS);
+            let p = bitfield_interleave(Vector(v));
+            assert_eq!(p.into(), naive_interleave(v.map(Into::into), kept));
+            let back: Vector<T, N> = bitfield_deinterleave(p);
+            for (x, y) in back.0.into_iter().zip(v) {
+                assert_eq!(x.into(), y.into() & (u64::MAX >> (64 - kept)));
+            }
+        }
+    }
+
+    #[test]
+    fn interleave_matches_per_bit_reference() {
+        let mut seed = 1;
+        check::<u8, 2>(&mut seed);
+        check::<u8, 3>(&mut seed);
+        check::<u8, 4>(&mut seed);
+        check::<u16, 2>(&mut seed);
+        check::<u16, 3>(&mut seed);
+        check::<u16, 4>(&mut seed);
+        check::<u32, 2>(&mut seed);
+        check::<u32, 3>(&mut seed);
+        assert_eq!(bitfield_interleave(uvec2::new(u32::MAX, 0)), 0x5555_5555_5555_5555);
+        assert_eq!(bitfield_interleave(Vector([0u32, 0, u32::MAX])), 0x4924_9249_2492_4924);
+        assert_eq!(bitfield_interleave(Vector([u32::MAX, 0, 0])), 0x1249_2492_4924_9249);
+    }
+
+    #[test]
+    fn masks() {
+        assert_eq!(mask(0u32), 0);
+        assert_eq!(mask(1u32), 1);
+        assert_eq!(mask(31u32), 0x7fff_ffff);
+        assert_eq!(mask(32u32), u32::MAX);
+        assert_eq!(mask(8u8), u8::MAX);
+        assert_eq!(mask(63u64), u64::MAX >> 1);
+        assert_eq!(mask(32i32), -1);
+        assert_eq!(mask(31i32), i32::MAX);
+        assert_eq!(uvec2::new(0, 4).mask(), uvec2::new(0, 0xf));
+    }
+
+    #[test]
+    fn rotations() {
+        assert_eq!(bitfield_rotate_left(0x8000_0001u32, 0), 0x8000_0001);
+        assert_eq!(bitfield_rotate_left(0x8000_0001u32, 1), 3);
+        assert_eq!(bitfield_rotate_left(0x8000_0001u32, 32), 0x8000_0001);
+        assert_eq!(bitfield_rotate_right(0x8000_0001u32, 1), 0xc000_0000);
+        assert_eq!(bitfield_rotate_right(0x12u8, 4), 0x21);
+        assert_eq!(bitfield_rotate_right(-2i32, 1), i32::MAX);
+        assert_eq!(uvec2::new(1, 2).bitfield_rotate_left(31), uvec2::new(0x8000_0000, 1));
+    }
+
+    #[test]
+    fn fills() {
+        assert_eq!(bitfield_fill_one(0u32, 4, 0), 0);
+        assert_eq!(bitfield_fill_one(0u32, 0, 32), u32::MAX);
+        assert_eq!(bitfield_fill_one(0u32, 4, 8), 0xff0);
+        assert_eq!(bitfield_fill_one(0u32, 24, 8), 0xff00_0000);
+        assert_eq!(bitfield_fill_one(0i8, 7, 1), i8::MIN);
+        assert_eq!(bitfield_fill_zero(u32::MAX, 4, 0), u32::MAX);
+        assert_eq!(bitfield_fill_zero(u32::MAX, 0, 32), 0);
+        assert_eq!(bitfield_fill_zero(u32::MAX, 4, 8), 0xffff_f00f);
+        assert_eq!(bitfield_fill_zero(-1i16, 15, 1), i16::MAX);
+        assert_eq!(uvec2::new(0, 0xff).bitfield_fill_zero(0, 4), uvec2::new(0, 0xf0));
+        assert_eq!(uvec2::new(0, 0xff).bitfield_fill_one(8, 4), uvec2::new(0xf00, 0xfff));
+    }
+}
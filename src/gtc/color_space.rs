@@ -0,0 +1,102 @@
+use crate::{vec3, vec4, Float, Number};
+
+// Colors are either rgb or rgba, and conversions leave alpha untouched.
+pub trait Color: Copy {
+    type Scalar: Float;
+
+    fn rgb(self) -> vec3<Self::Scalar>;
+    fn with_rgb(self, rgb: vec3<Self::Scalar>) -> Self;
+}
+
+impl<T: Float> Color for vec3<T> {
+    type Scalar = T;
+
+    #[inline]
+    fn rgb(self) -> vec3<T> {
+        self
+    }
+
+    #[inline]
+    fn with_rgb(self, rgb: vec3<T>) -> Self {
+        rgb
+    }
+}
+
+impl<T: Float> Color for vec4<T> {
+    type Scalar = T;
+
+    #[inline]
+    fn rgb(self) -> vec3<T> {
+        vec3::new(self.x, self.y, self.z)
+    }
+
+    #[inline]
+    fn with_rgb(self, rgb: vec3<T>) -> Self {
+        vec4::new(rgb.x, rgb.y, rgb.z, self.w)
+    }
+}
+
+#[inline]
+pub(crate) fn map_rgb<C: Color>(color: C, f: impl FnOnce(vec3<C::Scalar>) -> vec3<C::Scalar>) -> C {
+    color.with_rgb(f(color.rgb()))
+}
+
+// The linear input is clamped to [0, 1] first.
+#[inline]
+pub fn convert_linear_to_srgb_with_gamma<C: Color>(color: C, gamma: C::Scalar) -> C {
+    let gamma_correction = C::Scalar::ONE / gamma;
+    let threshold = C::Scalar::from_f64(0.0031308);
+    let (low, high, offset) = (C::Scalar::from_f64(12.92), C::Scalar::from_f64(1.055), C::Scalar::from_f64(0.055));
+    map_rgb(color, |rgb| rgb.map(|c| {
+        let c = if c > C::Scalar::ONE { C::Scalar::ONE } else if c > C::Scalar::ZERO { c } else { C::Scalar::ZERO };
+        if c < threshold { c * low } else { c.powf(gamma_correction) * high - offset }
+    }))
+}
+
+#[inline]
+pub fn convert_linear_to_srgb<C: Color>(color: C) -> C {
+    convert_linear_to_srgb_with_gamma(color, C::Scalar::from_f64(2.4))
+}
+
+#[inline]
+pub fn convert_srgb_to_linear_with_gamma<C: Color>(color: C, gamma: C::Scalar) -> C {
+    let threshold = C::Scalar::from_f64(0.04045);
+    let (low, high, offset) = (C::Scalar::from_f64(1.0 / 12.92), C::Scalar::from_f64(1.0 / 1.055), C::Scalar::from_f64(0.055));
+    map_rgb(color, |rgb| rgb.map(|c| {
+        if c <= threshold { c * low } else { ((c + offset) * high).powf(gamma) }
+    }))
+}
+
+#[inline]
+pub fn convert_srgb_to_linear<C: Color>(color: C) -> C {
+    convert_srgb_to_linear_with_gamma(color, C::Scalar::from_f64(2.4))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{convert_linear_to_srgb, convert_srgb_to_linear, vec3, vec4};
+
+    #[test]
+    fn srgb_reference_values() {
+        // The sRGB transfer function at mid grey and at the linear segment.
+        let srgb = convert_linear_to_srgb(vec3::new(0.5f64, 0.0031308 / 2.0, 1.0));
+        assert!((srgb - vec3::new(0.735_356_983_052_449_5, 0.0031308 / 2.0 * 12.92, 1.0)).0.iter().all(|d| d.abs() < 1e-12));
+        let linear = convert_srgb_to_linear(vec3::new(0.5f64, 0.04, 0.0));
+        assert!((linear - vec3::new(0.214_041_140_482_232_55, 0.04 / 12.92, 0.0)).0.iter().all(|d| d.abs() < 1e-12));
+    }
+
+    #[test]
+    fn srgb_round_trips() {
+        for i in 0..=100 {
+            let c = i as f64 / 100.0;
+            let color = vec4::new(c, 1.0 - c, c * c, 0.25);
+            let round_trip = convert_srgb_to_linear(convert_linear_to_srgb(color));
+            assert!((round_trip - color).0.iter().all(|d| d.abs() < 1e-12));
+            let round_trip = convert_linear_to_srgb(convert_srgb_to_linear(color));
+            assert!((round_trip - color).0.iter().all(|d| d.abs() < 1e-12));
+        }
+        // Linear values outside [0, 1] are clamped.
+        let clamped = convert_linear_to_srgb(vec3::new(-0.5f64, 2.0, 1.0));
+        assert!((clamped - vec3::new(0.0, 1.0, 1.0)).0.iter().all(|d| d.abs() < 1e-12));
+    }
+}
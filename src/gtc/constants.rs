@@ -0,0 +1,135 @@
+use core::f64::consts;
+
+use crate::{half, Float, Number};
+
+macro_rules! constants {
+    ($($name:ident, $konst:ident = $value:expr;)*) => {
+        pub trait FloatConstants: Float {
+            $(const $konst: Self;)*
+        }
+
+        // The values are spelled out in f64 and rounded once to f32.
+        impl FloatConstants for f32 {
+            $(const $konst: Self = {
+                let value: f64 = $value;
+                value as f32
+            };)*
+        }
+
+        impl FloatConstants for f64 {
+            $(const $konst: Self = $value;)*
+        }
+
+        impl FloatConstants for half {
+            $(const $konst: Self = half::from_f64($value);)*
+        }
+
+        $(
+            #[inline]
+            pub const fn $name<T: FloatConstants>() -> T {
+                T::$konst
+            }
+        )*
+    }
+}
+
+constants! {
+    pi, PI = consts::PI;
+    two_pi, TWO_PI = consts::TAU;
+    tau, TAU = consts::TAU;
+    root_pi, ROOT_PI = 1.772453850905516;
+    half_pi, HALF_PI = consts::FRAC_PI_2;
+    three_over_two_pi, THREE_OVER_TWO_PI = 4.71238898038469;
+    quarter_pi, QUARTER_PI = consts::FRAC_PI_4;
+    one_over_pi, ONE_OVER_PI = consts::FRAC_1_PI;
+    one_over_two_pi, ONE_OVER_TWO_PI = 0.15915494309189535;
+    two_over_pi, TWO_OVER_PI = consts::FRAC_2_PI;
+    four_over_pi, FOUR_OVER_PI = 1.2732395447351628;
+    two_over_root_pi, TWO_OVER_ROOT_PI = consts::FRAC_2_SQRT_PI;
+    one_over_root_two, ONE_OVER_ROOT_TWO = consts::FRAC_1_SQRT_2;
+    root_half_pi, ROOT_HALF_PI = 1.2533141373155003;
+    root_two_pi, ROOT_TWO_PI = 2.5066282746310005;
+    root_ln_four, ROOT_LN_FOUR = 1.1774100225154747;
+    e, E = consts::E;
+    euler, EULER = 0.5772156649015329;
+    root_two, ROOT_TWO = consts::SQRT_2;
+    root_three, ROOT_THREE = 1.7320508075688772;
+    root_five, ROOT_FIVE = 2.23606797749979;
+    ln_two, LN_TWO = consts::LN_2;
+    ln_ten, LN_TEN = consts::LN_10;
+    ln_ln_two, LN_LN_TWO = -0.36651292058166435;
+    third, THIRD = 0.3333333333333333;
+    two_thirds, TWO_THIRDS = 0.6666666666666666;
+    golden_ratio, GOLDEN_RATIO = 1.618033988749895;
+    cos_one_over_two, COS_ONE_OVER_TWO = 0.8775825618903728;
+}
+
+#[inline]
+pub const fn epsilon<T: Float>() -> T {
+    T::EPSILON
+}
+
+#[inline]
+pub const fn zero<T: Number>() -> T {
+    T::ZERO
+}
+
+#[inline]
+pub const fn one<T: Number>() -> T {
+    T::ONE
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::PI;
+
+    use crate::{gtc::constants::*, half, Float};
+
+    #[test]
+    fn constants_match_their_definitions() {
+        let one = 1.0f64;
+        // All but EULER, which has no closed form.
+        let expressions: [(f64, f64); 27] = [
+            (pi(), PI),
+            (two_pi(), 2.0 * PI),
+            (tau(), 2.0 * PI),
+            (root_pi(), PI.sqrt()),
+            (half_pi(), PI / 2.0),
+            (three_over_two_pi(), 3.0 * PI / 2.0),
+            (quarter_pi(), PI / 4.0),
+            (one_over_pi(), 1.0 / PI),
+            (one_over_two_pi(), 1.0 / (2.0 * PI)),
+            (two_over_pi(), 2.0 / PI),
+            (four_over_pi(), 4.0 / PI),
+            (two_over_root_pi(), 2.0 / PI.sqrt()),
+            (one_over_root_two(), 1.0 / 2.0.sqrt()),
+            (root_half_pi(), (PI / 2.0).sqrt()),
+            (root_two_pi(), (2.0 * PI).sqrt()),
+            (root_ln_four(), 4.0.ln().sqrt()),
+            (e(), one.exp()),
+            (root_two(), 2.0.sqrt()),
+            (root_three(), 3.0.sqrt()),
+            (root_five(), 5.0.sqrt()),
+            (ln_two(), 2.0.ln()),
+            (ln_ten(), 10.0.ln()),
+            (ln_ln_two(), 2.0.ln().ln()),
+            (third(), 1.0 / 3.0),
+            (two_thirds(), 2.0 / 3.0),
+            (golden_ratio(), (1.0 + 5.0.sqrt()) / 2.0),
+            (cos_one_over_two(), 0.5.cos()),
+        ];
+        for (i, (constant, expression)) in expressions.into_iter().enumerate() {
+            // The expressions round at every step, so allow an ulp.
+            assert!((constant - expression).abs() <= constant.abs() * f64::EPSILON, "{i}: {constant} != {expression}");
+        }
+    }
+
+    #[test]
+    fn narrower_types_round_once() {
+        assert_eq!(root_two_pi::<f32>(), root_two_pi::<f64>() as f32);
+        assert_eq!(three_over_two_pi::<f32>(), three_over_two_pi::<f64>() as f32);
+        assert_eq!(ln_ln_two::<f32>(), ln_ln_two::<f64>() as f32);
+        assert_eq!(root_two_pi::<half>(), half::from_f64(root_two_pi::<f64>()));
+        assert_eq!(pi::<half>().to_bits(), 0x4248);
+    }
+}
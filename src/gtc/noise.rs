@@ -0,0 +1,652 @@
+use crate::{vec1, vec2, vec3, vec4, Float, Vector};
+
+// A port of GLM's noise, itself based on Stefan Gustavson and Ian McEwan's
+// GLSL noise. The operations follow the reference order, which the tests
+// check against a C++ transcription of GLM rather than GLM itself.
+
+#[inline]
+fn floor<T: Float, const N: usize>(x: Vector<T, N>) -> Vector<T, N> {
+    x.map(Float::floor)
+}
+
+#[inline]
+fn fract<T: Float, const N: usize>(x: Vector<T, N>) -> Vector<T, N> {
+    x - floor(x)
+}
+
+#[inline]
+fn abs<T: Float, const N: usize>(x: Vector<T, N>) -> Vector<T, N> {
+    x.map(Float::abs)
+}
+
+#[inline]
+fn max<T: Float, const N: usize>(x: Vector<T, N>, y: T) -> Vector<T, N> {
+    x.map(|x| if x < y { y } else { x })
+}
+
+#[inline]
+fn step<T: Float, const N: usize>(edge: Vector<T, N>, x: Vector<T, N>) -> Vector<T, N> {
+    let mut result = x;
+    for i in 0..N {
+        result.0[i] = if x.0[i] < edge.0[i] { T::ZERO } else { T::ONE };
+    }
+    result
+}
+
+#[inline]
+fn mix<T: Float, const N: usize>(x: Vector<T, N>, y: Vector<T, N>, a: T) -> Vector<T, N> {
+    x * (T::ONE - a) + y * a
+}
+
+#[inline]
+fn modulo<T: Float, const N: usize>(x: Vector<T, N>, y: Vector<T, N>) -> Vector<T, N> {
+    x - y * floor(x / y)
+}
+
+#[inline]
+fn mod289<T: Float, const N: usize>(x: Vector<T, N>) -> Vector<T, N> {
+    x - floor(x * (T::ONE / T::from_f64(289.0))) * T::from_f64(289.0)
+}
+
+#[inline]
+fn permute<T: Float, const N: usize>(x: Vector<T, N>) -> Vector<T, N> {
+    mod289((x * T::from_f64(34.0) + T::ONE) * x)
+}
+
+#[inline]
+fn taylor_inv_sqrt<T: Float, const N: usize>(r: Vector<T, N>) -> Vector<T, N> {
+    Vector::splat(T::from_f64(1.79284291400159)) - r * T::from_f64(0.85373472095314)
+}
+
+#[inline]
+fn fade<T: Float, const N: usize>(t: Vector<T, N>) -> Vector<T, N> {
+    (t * t * t) * (t * (t * T::from_f64(6.0) - T::from_f64(15.0)) + T::from_f64(10.0))
+}
+
+#[inline]
+fn grad4<T: Float>(j: T, ip: vec4<T>) -> vec4<T> {
+    let ip3 = vec3::new(ip.x, ip.y, ip.z);
+    let p = floor(fract(vec3::splat(j) * ip3) * T::from_f64(7.0)) * ip.z - T::ONE;
+    let w = T::from_f64(1.5) - abs(p).dot(vec3::splat(T::ONE));
+    let s = vec4::new(p.x, p.y, p.z, w).map(|x| if x < T::ZERO { T::ONE } else { T::ZERO });
+    let p = p + (vec3::new(s.x, s.y, s.z) * T::from_f64(2.0) - T::ONE) * s.w;
+    vec4::new(p.x, p.y, p.z, w)
+}
+
+pub trait Noise: Copy {
+    type Scalar: Float;
+
+    fn perlin(self) -> Self::Scalar;
+    fn perlin_periodic(self, rep: Self) -> Self::Scalar;
+    fn simplex(self) -> Self::Scalar;
+}
+
+#[inline]
+pub fn perlin<V: Noise>(position: V) -> V::Scalar {
+    position.perlin()
+}
+
+#[inline]
+pub fn perlin_periodic<V: Noise>(position: V, rep: V) -> V::Scalar {
+    position.perlin_periodic(rep)
+}
+
+#[inline]
+pub fn simplex<V: Noise>(position: V) -> V::Scalar {
+    position.simplex()
+}
+
+#[inline]
+fn perlin_2d<T: Float>(pi: vec4<T>, pf: vec4<T>) -> T {
+    let ix = vec4::new(pi.x, pi.z, pi.x, pi.z);
+    let iy = vec4::new(pi.y, pi.y, pi.w, pi.w);
+    let fx = vec4::new(pf.x, pf.z, pf.x, pf.z);
+    let fy = vec4::new(pf.y, pf.y, pf.w, pf.w);
+
+    let i = permute(permute(ix) + iy);
+
+    let gx = fract(i / T::from_f64(41.0)) * T::from_f64(2.0) - T::ONE;
+    let gy = abs(gx) - T::from_f64(0.5);
+    let tx = floor(gx + T::from_f64(0.5));
+    let gx = gx - tx;
+
+    let g00 = vec2::new(gx.x, gy.x);
+    let g10 = vec2::new(gx.y, gy.y);
+    let g01 = vec2::new(gx.z, gy.z);
+    let g11 = vec2::new(gx.w, gy.w);
+
+    let norm = taylor_inv_sqrt(vec4::new(g00.dot(g00), g01.dot(g01), g10.dot(g10), g11.dot(g11)));
+    let g00 = g00 * norm.x;
+    let g01 = g01 * norm.y;
+    let g10 = g10 * norm.z;
+    let g11 = g11 * norm.w;
+
+    let n00 = g00.dot(vec2::new(fx.x, fy.x));
+    let n10 = g10.dot(vec2::new(fx.y, fy.y));
+    let n01 = g01.dot(vec2::new(fx.z, fy.z));
+    let n11 = g11.dot(vec2::new(fx.w, fy.w));
+
+    let fade_xy = fade(vec2::new(pf.x, pf.y));
+    let n_x = mix(vec2::new(n00, n01), vec2::new(n10, n11), fade_xy.x);
+    let n_xy = mix(vec2::new(n_x.x, T::ZERO), vec2::new(n_x.y, T::ZERO), fade_xy.y).x;
+    T::from_f64(2.3) * n_xy
+}
+
+#[inline]
+fn perlin_3d<T: Float>(pi0: vec3<T>, pi1: vec3<T>, pf0: vec3<T>, by_seven: impl Fn(vec4<T>) -> vec4<T>) -> T {
+    let pf1 = pf0 - T::ONE;
+    let ix = vec4::new(pi0.x, pi1.x, pi0.x, pi1.x);
+    let iy = vec4::new(pi0.y, pi0.y, pi1.y, pi1.y);
+    let iz0 = vec4::splat(pi0.z);
+    let iz1 = vec4::splat(pi1.z);
+
+    let ixy = permute(permute(ix) + iy);
+    let ixy0 = permute(ixy + iz0);
+    let ixy1 = permute(ixy + iz1);
+
+    let gradients = |ixy: vec4<T>| {
+        let gx = by_seven(ixy);
+        let gy = fract(by_seven(floor(gx))) - T::from_f64(0.5);
+        let gx = fract(gx);
+        let gz = vec4::splat(T::from_f64(0.5)) - abs(gx) - abs(gy);
+        let sz = step(gz, vec4::splat(T::ZERO));
+        let gx = gx - sz * (step(vec4::splat(T::ZERO), gx) - T::from_f64(0.5));
+        let gy = gy - sz * (step(vec4::splat(T::ZERO), gy) - T::from_f64(0.5));
+        (gx, gy, gz)
+    };
+    let (gx0, gy0, gz0) = gradients(ixy0);
+    let (gx1, gy1, gz1) = gradients(ixy1);
+
+    let g000 = vec3::new(gx0.x, gy0.x, gz0.x);
+    let g100 = vec3::new(gx0.y, gy0.y, gz0.y);
+    let g010 = vec3::new(gx0.z, gy0.z, gz0.z);
+    let g110 = vec3::new(gx0.w, gy0.w, gz0.w);
+    let g001 = vec3::new(gx1.x, gy1.x, gz1.x);
+    let g101 = vec3::new(gx1.y, gy1.y, gz1.y);
+    let g011 = vec3::new(gx1.z, gy1.z, gz1.z);
+    let g111 = vec3::new(gx1.w, gy1.w, gz1.w);
+
+    let norm0 = taylor_inv_sqrt(vec4::new(g000.dot(g000), g010.dot(g010), g100.dot(g100), g110.dot(g110)));
+    let g000 = g000 * norm0.x;
+    let g010 = g010 * norm0.y;
+    let g100 = g100 * norm0.z;
+    let g110 = g110 * norm0.w;
+    let norm1 = taylor_inv_sqrt(vec4::new(g001.dot(g001), g011.dot(g011), g101.dot(g101), g111.dot(g111)));
+    let g001 = g001 * norm1.x;
+    let g011 = g011 * norm1.y;
+    let g101 = g101 * norm1.z;
+    let g111 = g111 * norm1.w;
+
+    let n000 = g000.dot(pf0);
+    let n100 = g100.dot(vec3::new(pf1.x, pf0.y, pf0.z));
+    let n010 = g010.dot(vec3::new(pf0.x, pf1.y, pf0.z));
+    let n110 = g110.dot(vec3::new(pf1.x, pf1.y, pf0.z));
+    let n001 = g001.dot(vec3::new(pf0.x, pf0.y, pf1.z));
+    let n101 = g101.dot(vec3::new(pf1.x, pf0.y, pf1.z));
+    let n011 = g011.dot(vec3::new(pf0.x, pf1.y, pf1.z));
+    let n111 = g111.dot(pf1);
+
+    let fade_xyz = fade(pf0);
+    let n_z = mix(vec4::new(n000, n100, n010, n110), vec4::new(n001, n101, n011, n111), fade_xyz.z);
+    let n_yz = mix(vec2::new(n_z.x, n_z.y), vec2::new(n_z.z, n_z.w), fade_xyz.y);
+    let n_xyz = mix(vec2::new(n_yz.x, T::ZERO), vec2::new(n_yz.y, T::ZERO), fade_xyz.x).x;
+    T::from_f64(2.2) * n_xyz
+}
+
+#[inline]
+fn perlin_4d<T: Float>(pi0: vec4<T>, pi1: vec4<T>, pf0: vec4<T>) -> T {
+    let pf1 = pf0 - T::ONE;
+    let ix = vec4::new(pi0.x, pi1.x, pi0.x, pi1.x);
+    let iy = vec4::new(pi0.y, pi0.y, pi1.y, pi1.y);
+    let iz0 = vec4::splat(pi0.z);
+    let iz1 = vec4::splat(pi1.z);
+    let iw0 = vec4::splat(pi0.w);
+    let iw1 = vec4::splat(pi1.w);
+
+    let ixy = permute(permute(ix) + iy);
+    let ixy0 = permute(ixy + iz0);
+    let ixy1 = permute(ixy + iz1);
+    let ixy00 = permute(ixy0 + iw0);
+    let ixy01 = permute(ixy0 + iw1);
+    let ixy10 = permute(ixy1 + iw0);
+    let ixy11 = permute(ixy1 + iw1);
+
+    let gradients = |ixy: vec4<T>| {
+        let gx = ixy / T::from_f64(7.0);
+        let gy = floor(gx) / T::from_f64(7.0);
+        let gz = floor(gy) / T::from_f64(6.0);
+        let gx = fract(gx) - T::from_f64(0.5);
+        let gy = fract(gy) - T::from_f64(0.5);
+        let gz = fract(gz) - T::from_f64(0.5);
+        let gw = vec4::splat(T::from_f64(0.75)) - abs(gx) - abs(gy) - abs(gz);
+        let sw = step(gw, vec4::splat(T::ZERO));
+        let gx = gx - sw * (step(vec4::splat(T::ZERO), gx) - T::from_f64(0.5));
+        let gy = gy - sw * (step(vec4::splat(T::ZERO), gy) - T::from_f64(0.5));
+        (gx, gy, gz, gw)
+    };
+    let (gx00, gy00, gz00, gw00) = gradients(ixy00);
+    let (gx01, gy01, gz01, gw01) = gradients(ixy01);
+    let (gx10, gy10, gz10, gw10) = gradients(ixy10);
+    let (gx11, gy11, gz11, gw11) = gradients(ixy11);
+
+    let g0000 = vec4::new(gx00.x, gy00.x, gz00.x, gw00.x);
+    let g1000 = vec4::new(gx00.y, gy00.y, gz00.y, gw00.y);
+    let g0100 = vec4::new(gx00.z, gy00.z, gz00.z, gw00.z);
+    let g1100 = vec4::new(gx00.w, gy00.w, gz00.w, gw00.w);
+    let g0010 = vec4::new(gx10.x, gy10.x, gz10.x, gw10.x);
+    let g1010 = vec4::new(gx10.y, gy10.y, gz10.y, gw10.y);
+    let g0110 = vec4::new(gx10.z, gy10.z, gz10.z, gw10.z);
+    let g1110 = vec4::new(gx10.w, gy10.w, gz10.w, gw10.w);
+    let g0001 = vec4::new(gx01.x, gy01.x, gz01.x, gw01.x);
+    let g1001 = vec4::new(gx01.y, gy01.y, gz01.y, gw01.y);
+    let g0101 = vec4::new(gx01.z, gy01.z, gz01.z, gw01.z);
+    let g1101 = vec4::new(gx01.w, gy01.w, gz01.w, gw01.w);
+    let g0011 = vec4::new(gx11.x, gy11.x, gz11.x, gw11.x);
+    let g1011 = vec4::new(gx11.y, gy11.y, gz11.y, gw11.y);
+    let g0111 = vec4::new(gx11.z, gy11.z, gz11.z, gw11.z);
+    let g1111 = vec4::new(gx11.w, gy11.w, gz11.w, gw11.w);
+
+    let norm00 = taylor_inv_sqrt(vec4::new(g0000.dot(g0000), g0100.dot(g0100), g1000.dot(g1000), g1100.dot(g1100)));
+    let g0000 = g0000 * norm00.x;
+    let g0100 = g0100 * norm00.y;
+    let g1000 = g1000 * norm00.z;
+    let g1100 = g1100 * norm00.w;
+
+    let norm01 = taylor_inv_sqrt(vec4::new(g0001.dot(g0001), g0101.dot(g0101), g1001.dot(g1001), g1101.dot(g1101)));
+    let g0001 = g0001 * norm01.x;
+    let g0101 = g0101 * norm01.y;
+    let g1001 = g1001 * norm01.z;
+    let g1101 = g1101 * norm01.w;
+
+    let norm10 = taylor_inv_sqrt(vec4::new(g0010.dot(g0010), g0110.dot(g0110), g1010.dot(g1010), g1110.dot(g1110)));
+    let g0010 = g0010 * norm10.x;
+    let g0110 = g0110 * norm10.y;
+    let g1010 = g1010 * norm10.z;
+    let g1110 = g1110 * norm10.w;
+
+    let norm11 = taylor_inv_sqrt(vec4::new(g0011.dot(g0011), g0111.dot(g0111), g1011.dot(g1011), g1111.dot(g1111)));
+    let g0011 = g0011 * norm11.x;
+    let g0111 = g0111 * norm11.y;
+    let g1011 = g1011 * norm11.z;
+    let g1111 = g1111 * norm11.w;
+
+    let n0000 = g0000.dot(pf0);
+    let n1000 = g1000.dot(vec4::new(pf1.x, pf0.y, pf0.z, pf0.w));
+    let n0100 = g0100.dot(vec4::new(pf0.x, pf1.y, pf0.z, pf0.w));
+    let n1100 = g1100.dot(vec4::new(pf1.x, pf1.y, pf0.z, pf0.w));
+    let n0010 = g0010.dot(vec4::new(pf0.x, pf0.y, pf1.z, pf0.w));
+    let n1010 = g1010.dot(vec4::new(pf1.x, pf0.y, pf1.z, pf0.w));
+    let n0110 = g0110.dot(vec4::new(pf0.x, pf1.y, pf1.z, pf0.w));
+    let n1110 = g1110.dot(vec4::new(pf1.x, pf1.y, pf1.z, pf0.w));
+    let n0001 = g0001.dot(vec4::new(pf0.x, pf0.y, pf0.z, pf1.w));
+    let n1001 = g1001.dot(vec4::new(pf1.x, pf0.y, pf0.z, pf1.w));
+    let n0101 = g0101.dot(vec4::new(pf0.x, pf1.y, pf0.z, pf1.w));
+    let n1101 = g1101.dot(vec4::new(pf1.x, pf1.y, pf0.z, pf1.w));
+    let n0011 = g0011.dot(vec4::new(pf0.x, pf0.y, pf1.z, pf1.w));
+    let n1011 = g1011.dot(vec4::new(pf1.x, pf0.y, pf1.z, pf1.w));
+    let n0111 = g0111.dot(vec4::new(pf0.x, pf1.y, pf1.z, pf1.w));
+    let n1111 = g1111.dot(pf1);
+
+    let fade_xyzw = fade(pf0);
+    let n_0w = mix(vec4::new(n0000, n1000, n0100, n1100), vec4::new(n0001, n1001, n0101, n1101), fade_xyzw.w);
+    let n_1w = mix(vec4::new(n0010, n1010, n0110, n1110), vec4::new(n0011, n1011, n0111, n1111), fade_xyzw.w);
+    let n_zw = mix(n_0w, n_1w, fade_xyzw.z);
+    let n_yzw = mix(vec2::new(n_zw.x, n_zw.y), vec2::new(n_zw.z, n_zw.w), fade_xyzw.y);
+    let n_xyzw = mix(vec2::new(n_yzw.x, T::ZERO), vec2::new(n_yzw.y, T::ZERO), fade_xyzw.x).x;
+    T::from_f64(2.2) * n_xyzw
+}
+
+impl<T: Float> Noise for vec2<T> {
+    type Scalar = T;
+
+    #[inline]
+    fn perlin(self) -> T {
+        let p = vec4::new(self.x, self.y, self.x, self.y);
+        let offset = vec4::new(T::ZERO, T::ZERO, T::ONE, T::ONE);
+        let pi = floor(p) + offset;
+        let pf = fract(p) - offset;
+        perlin_2d(modulo(pi, vec4::splat(T::from_f64(289.0))), pf)
+    }
+
+    #[inline]
+    fn perlin_periodic(self, rep: Self) -> T {
+        let p = vec4::new(self.x, self.y, self.x, self.y);
+        let offset = vec4::new(T::ZERO, T::ZERO, T::ONE, T::ONE);
+        let pi = floor(p) + offset;
+        let pf = fract(p) - offset;
+        let pi = modulo(pi, vec4::new(rep.x, rep.y, rep.x, rep.y));
+        perlin_2d(modulo(pi, vec4::splat(T::from_f64(289.0))), pf)
+    }
+
+    #[inline]
+    fn simplex(self) -> T {
+        let v = self;
+        let c = vec4::new(
+            T::from_f64(0.211324865405187),
+            T::from_f64(0.366025403784439),
+            T::from_f64(-0.577350269189626),
+            T::from_f64(0.024390243902439));
+
+        // First corner
+        let i = floor(v + v.dot(vec2::splat(c.y)));
+        let x0 = v - i + i.dot(vec2::splat(c.x));
+
+        // Other corners
+        let i1 = if x0.x > x0.y { vec2::new(T::ONE, T::ZERO) } else { vec2::new(T::ZERO, T::ONE) };
+        let x12 = vec4::new(x0.x, x0.y, x0.x, x0.y) + vec4::new(c.x, c.x, c.z, c.z);
+        let x12 = vec4::new(x12.x - i1.x, x12.y - i1.y, x12.z, x12.w);
+
+        // Permutations
+        let i = modulo(i, vec2::splat(T::from_f64(289.0)));
+        let p = permute(
+            permute(vec3::new(T::ZERO, i1.y, T::ONE) + i.y)
+            + i.x + vec3::new(T::ZERO, i1.x, T::ONE));
+
+        let m = max(vec3::splat(T::from_f64(0.5)) - vec3::new(
+            x0.dot(x0),
+            vec2::new(x12.x, x12.y).dot(vec2::new(x12.x, x12.y)),
+            vec2::new(x12.z, x12.w).dot(vec2::new(x12.z, x12.w))), T::ZERO);
+        let m = m * m;
+        let m = m * m;
+
+        // Gradients: 41 points uniformly over a line, mapped onto a diamond.
+        let x = fract(p * c.w) * T::from_f64(2.0) - T::ONE;
+        let h = abs(x) - T::from_f64(0.5);
+        let ox = floor(x + T::from_f64(0.5));
+        let a0 = x - ox;
+
+        // Normalise gradients implicitly by scaling m.
+        let m = m * (vec3::splat(T::from_f64(1.79284291400159)) - (a0 * a0 + h * h) * T::from_f64(0.85373472095314));
+
+        // Compute final noise value at P
+        let g = vec3::new(
+            a0.x * x0.x + h.x * x0.y,
+            a0.y * x12.x + h.y * x12.y,
+            a0.z * x12.z + h.z * x12.w);
+        T::from_f64(130.0) * m.dot(g)
+    }
+}
+
+impl<T: Float> Noise for vec3<T> {
+    type Scalar = T;
+
+    #[inline]
+    fn perlin(self) -> T {
+        let pi0 = floor(self);
+        let pi1 = pi0 + T::ONE;
+        perlin_3d(mod289(pi0), mod289(pi1), fract(self), |x| x * T::from_f64(1.0 / 7.0))
+    }
+
+    #[inline]
+    fn perlin_periodic(self, rep: Self) -> T {
+        let pi0 = modulo(floor(self), rep);
+        let pi1 = modulo(pi0 + T::ONE, rep);
+        let pi0 = mod289(pi0);
+        let pi1 = mod289(pi1);
+        perlin_3d(pi0, pi1, fract(self), |x| x / T::from_f64(7.0))
+    }
+
+    #[inline]
+    fn simplex(self) -> T {
+        let v = self;
+        let c = vec2::new(T::from_f64(1.0 / 6.0), T::from_f64(1.0 / 3.0));
+        let d = vec4::new(T::ZERO, T::from_f64(0.5), T::ONE, T::from_f64(2.0));
+
+        // First corner
+        let i = floor(v + v.dot(vec3::splat(c.y)));
+        let x0 = v - i + i.dot(vec3::splat(c.x));
+
+        // Other corners
+        let g = step(vec3::new(x0.y, x0.z, x0.x), x0);
+        let l = vec3::splat(T::ONE) - g;
+        let lzxy = vec3::new(l.z, l.x, l.y);
+        let i1: vec3<T> = Vector(core::array::from_fn(|k| if lzxy.0[k] < g.0[k] { lzxy.0[k] } else { g.0[k] }));
+        let i2: vec3<T> = Vector(core::array::from_fn(|k| if g.0[k] < lzxy.0[k] { lzxy.0[k] } else { g.0[k] }));
+
+        let x1 = x0 - i1 + c.x;
+        let x2 = x0 - i2 + c.y;
+        let x3 = x0 - d.y;
+
+        // Permutations
+        let i = mod289(i);
+        let p = permute(permute(permute(
+            vec4::new(T::ZERO, i1.z, i2.z, T::ONE) + i.z)
+            + i.y + vec4::new(T::ZERO, i1.y, i2.y, T::ONE))
+            + i.x + vec4::new(T::ZERO, i1.x, i2.x, T::ONE));
+
+        // Gradients: 7x7 points over a square, mapped onto an octahedron.
+        // GLM spells this 0.142857142857, which rounds to the same f32 but is
+        // short enough in f64 for floor(j / 7) to land on the wrong integer.
+        let n_ = T::from_f64(1.0 / 7.0);
+        let ns = vec3::new(d.w, d.y, d.z) * n_ - vec3::new(d.x, d.z, d.x);
+
+        let j = p - floor(p * ns.z * ns.z) * T::from_f64(49.0);
+
+        let x_ = floor(j * ns.z);
+        let y_ = floor(j - x_ * T::from_f64(7.0));
+
+        let x = x_ * ns.x + ns.y;
+        let y = y_ * ns.x + ns.y;
+        let h = vec4::splat(T::ONE) - abs(x) - abs(y);
+
+        let b0 = vec4::new(x.x, x.y, y.x, y.y);
+        let b1 = vec4::new(x.z, x.w, y.z, y.w);
+
+        let s0 = floor(b0) * T::from_f64(2.0) + T::ONE;
+        let s1 = floor(b1) * T::from_f64(2.0) + T::ONE;
+        let sh = -step(h, vec4::splat(T::ZERO));
+
+        let a0 = vec4::new(b0.x, b0.z, b0.y, b0.w) + vec4::new(s0.x, s0.z, s0.y, s0.w) * vec4::new(sh.x, sh.x, sh.y, sh.y);
+        let a1 = vec4::new(b1.x, b1.z, b1.y, b1.w) + vec4::new(s1.x, s1.z, s1.y, s1.w) * vec4::new(sh.z, sh.z, sh.w, sh.w);
+
+        let p0 = vec3::new(a0.x, a0.y, h.x);
+        let p1 = vec3::new(a0.z, a0.w, h.y);
+        let p2 = vec3::new(a1.x, a1.y, h.z);
+        let p3 = vec3::new(a1.z, a1.w, h.w);
+
+        // Normalise gradients
+        let norm = taylor_inv_sqrt(vec4::new(p0.dot(p0), p1.dot(p1), p2.dot(p2), p3.dot(p3)));
+        let p0 = p0 * norm.x;
+        let p1 = p1 * norm.y;
+        let p2 = p2 * norm.z;
+        let p3 = p3 * norm.w;
+
+        // Mix final noise value
+        let m = max(vec4::splat(T::from_f64(0.6)) - vec4::new(x0.dot(x0), x1.dot(x1), x2.dot(x2), x3.dot(x3)), T::ZERO);
+        let m = m * m;
+        T::from_f64(42.0) * (m * m).dot(vec4::new(p0.dot(x0), p1.dot(x1), p2.dot(x2), p3.dot(x3)))
+    }
+}
+
+impl<T: Float> Noise for vec4<T> {
+    type Scalar = T;
+
+    #[inline]
+    fn perlin(self) -> T {
+        let pi0 = floor(self);
+        let pi1 = pi0 + T::ONE;
+        let pi0 = modulo(pi0, vec4::splat(T::from_f64(289.0)));
+        let pi1 = modulo(pi1, vec4::splat(T::from_f64(289.0)));
+        perlin_4d(pi0, pi1, fract(self))
+    }
+
+    #[inline]
+    fn perlin_periodic(self, rep: Self) -> T {
+        let pi0 = modulo(floor(self), rep);
+        let pi1 = modulo(pi0 + T::ONE, rep);
+        perlin_4d(pi0, pi1, fract(self))
+    }
+
+    #[inline]
+    fn simplex(self) -> T {
+        let v = self;
+        let c = vec4::new(
+            T::from_f64(0.138196601125011),
+            T::from_f64(0.276393202250021),
+            T::from_f64(0.414589803375032),
+            T::from_f64(-0.447213595499958));
+        let f4 = T::from_f64(0.30901699437494745);
+
+        // First corner
+        let i = floor(v + v.dot(vec4::splat(f4)));
+        let x0 = v - i + i.dot(vec4::splat(c.x));
+
+        // Other corners, ranked as contributed by Bill Licea-Kane.
+        let is_x = step(vec3::new(x0.y, x0.z, x0.w), vec3::splat(x0.x));
+        let is_yz = step(vec3::new(x0.z, x0.w, x0.w), vec3::new(x0.y, x0.y, x0.z));
+        let mut i0 = vec4::new(is_x.x + is_x.y + is_x.z, T::ONE - is_x.x, T::ONE - is_x.y, T::ONE - is_x.z);
+        i0.y += is_yz.x + is_yz.y;
+        i0.z += T::ONE - is_yz.x;
+        i0.w += T::ONE - is_yz.y;
+        i0.z += is_yz.z;
+        i0.w += T::ONE - is_yz.z;
+
+        // i0 now contains the unique values 0, 1, 2, 3 in each channel.
+        let clamp = |x: vec4<T>| x.map(|x| if x < T::ZERO { T::ZERO } else if x > T::ONE { T::ONE } else { x });
+        let i3 = clamp(i0);
+        let i2 = clamp(i0 - T::ONE);
+        let i1 = clamp(i0 - T::from_f64(2.0));
+
+        let x1 = x0 - i1 + c.x;
+        let x2 = x0 - i2 + c.y;
+        let x3 = x0 - i3 + c.z;
+        let x4 = x0 + c.w;
+
+        // Permutations
+        let i = modulo(i, vec4::splat(T::from_f64(289.0)));
+        let permute1 = |x: T| permute(vec1::new(x)).x;
+        let j0 = permute1(permute1(permute1(permute1(i.w) + i.z) + i.y) + i.x);
+        let j1 = permute(permute(permute(permute(
+            vec4::new(i1.w, i2.w, i3.w, T::ONE) + i.w)
+            + i.z + vec4::new(i1.z, i2.z, i3.z, T::ONE))
+            + i.y + vec4::new(i1.y, i2.y, i3.y, T::ONE))
+            + i.x + vec4::new(i1.x, i2.x, i3.x, T::ONE));
+
+        // Gradients: 7x7x6 points over a cube, mapped onto a 4-cross polytope.
+        let ip = vec4::new(T::ONE / T::from_f64(294.0), T::ONE / T::from_f64(49.0), T::ONE / T::from_f64(7.0), T::ZERO);
+
+        let p0 = grad4(j0, ip);
+        let p1 = grad4(j1.x, ip);
+        let p2 = grad4(j1.y, ip);
+        let p3 = grad4(j1.z, ip);
+        let p4 = grad4(j1.w, ip);
+
+        // Normalise gradients
+        let norm = taylor_inv_sqrt(vec4::new(p0.dot(p0), p1.dot(p1), p2.dot(p2), p3.dot(p3)));
+        let p0 = p0 * norm.x;
+        let p1 = p1 * norm.y;
+        let p2 = p2 * norm.z;
+        let p3 = p3 * norm.w;
+        let p4 = p4 * taylor_inv_sqrt(vec1::new(p4.dot(p4))).x;
+
+        // Mix contributions from the five corners
+        let m0 = max(vec3::splat(T::from_f64(0.6)) - vec3::new(x0.dot(x0), x1.dot(x1), x2.dot(x2)), T::ZERO);
+        let m1 = max(vec2::splat(T::from_f64(0.6)) - vec2::new(x3.dot(x3), x4.dot(x4)), T::ZERO);
+        let m0 = m0 * m0;
+        let m1 = m1 * m1;
+        T::from_f64(49.0)
+            * ((m0 * m0).dot(vec3::new(p0.dot(x0), p1.dot(x1), p2.dot(x2)))
+                + (m1 * m1).dot(vec2::new(p3.dot(x3), p4.dot(x4))))
+    }
+}
+
+// Fractal sums of `octaves` layers of `noise`, each scaled in frequency by
+// `lacunarity` and in amplitude by `gain`.
+#[inline]
+pub fn fbm<T: Float, const N: usize>(
+    position: Vector<T, N>,
+    octaves: u32,
+    lacunarity: T,
+    gain: T,
+    noise: impl Fn(Vector<T, N>) -> T,
+) -> T {
+    let mut sum = T::ZERO;
+    let mut frequency = T::ONE;
+    let mut amplitude = T::ONE;
+    for _ in 0..octaves {
+        sum += noise(position * frequency) * amplitude;
+        frequency *= lacunarity;
+        amplitude *= gain;
+    }
+    sum
+}
+
+#[inline]
+pub fn turbulence<T: Float, const N: usize>(
+    position: Vector<T, N>,
+    octaves: u32,
+    lacunarity: T,
+    gain: T,
+    noise: impl Fn(Vector<T, N>) -> T,
+) -> T {
+    fbm(position, octaves, lacunarity, gain, |p| noise(p).abs())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{fbm, perlin, perlin_periodic, simplex, turbulence, vec2, vec3, vec4};
+
+    // Reference bits from a line-by-line C++ transcription of GLM 0.9.9's
+    // gtc/noise.inl, built with g++ in float without contraction. GLM itself
+    // was not at hand, so this checks the operation order against that
+    // transcription rather than against GLM's own output.
+    const SAMPLES: [([f32; 4], [u32; 6]); 5] = [
+        ([0.5, 0.25, 0.75, 0.125], [0xbe90bbaa, 0x3d4cdc27, 0x3ec6a2bf, 0xbe5f98aa, 0xbf2cc695, 0x3e8e9cb1]),
+        ([1.3, -2.7, 3.1, 0.9], [0x3f05412a, 0xbe4de5df, 0xbf0d6f9e, 0xbe9acb30, 0xbf19a3b0, 0xbf553ed4]),
+        ([-7.25, 4.5, -0.3, 12.6], [0xbe098088, 0xbe95cad2, 0xbe4917dd, 0x3ebd6f36, 0x3f125a71, 0xbc01f23a]),
+        ([42.17, 13.9, -5.55, -21.2], [0x3ea7f6b0, 0x3e783016, 0xbc733d2e, 0x3f1155a1, 0x3e55621e, 0xbe2055ec]),
+        ([301.4, -288.6, 150.05, 7.7], [0xbf2d99b0, 0x3de0ac96, 0x3eac17e8, 0xbe94acac, 0x3caee453, 0x3e502928]),
+    ];
+
+    #[test]
+    fn matches_transcription() {
+        for ([x, y, z, w], expected) in SAMPLES {
+            let actual = [
+                perlin(vec2::new(x, y)),
+                perlin(vec3::new(x, y, z)),
+                perlin(vec4::new(x, y, z, w)),
+                simplex(vec2::new(x, y)),
+                simplex(vec3::new(x, y, z)),
+                simplex(vec4::new(x, y, z, w)),
+            ];
+            assert_eq!(actual.map(f32::to_bits), expected, "{actual:?} at {:?}", [x, y, z, w]);
+        }
+    }
+
+    // Whole periods and dyadic fractions keep p + rep exact, so the lattice
+    // wraps onto the same cells with the same fractions.
+    #[test]
+    fn perlin_periodic_repeats() {
+        let points = [[0.375f32, 1.625, -2.25, 3.5], [-7.125, 0.5, 9.875, -0.0625], [100.75, -33.25, 5.5, 12.125]];
+        let (rep2, rep3, rep4) = (vec2::new(3.0f32, 5.0), vec3::new(4.0f32, 2.0, 7.0), vec4::new(6.0f32, 3.0, 5.0, 2.0));
+        for [x, y, z, w] in points {
+            let (p2, p3, p4) = (vec2::new(x, y), vec3::new(x, y, z), vec4::new(x, y, z, w));
+            for k in [1.0, 2.0, -1.0, -3.0] {
+                assert_eq!(perlin_periodic(p2 + rep2 * k, rep2), perlin_periodic(p2, rep2), "{p2:?} {k}");
+                assert_eq!(perlin_periodic(p3 + rep3 * k, rep3), perlin_periodic(p3, rep3), "{p3:?} {k}");
+                assert_eq!(perlin_periodic(p4 + rep4 * k, rep4), perlin_periodic(p4, rep4), "{p4:?} {k}");
+            }
+            // One period along a single axis is enough.
+            assert_eq!(perlin_periodic(p3 + vec3::new(0.0, 2.0, 0.0), rep3), perlin_periodic(p3, rep3));
+        }
+    }
+
+    #[test]
+    fn fbm_sums_octaves() {
+        let p = vec3::new(0.3f64, -1.7, 2.2);
+        assert_eq!(fbm(p, 0, 2.0, 0.5, perlin), 0.0);
+        assert_eq!(fbm(p, 1, 2.0, 0.5, perlin), perlin(p));
+        let expected = perlin(p) + perlin(p * 2.0) * 0.5 + perlin(p * 4.0) * 0.25 + perlin(p * 8.0) * 0.125;
+        assert_eq!(fbm(p, 4, 2.0, 0.5, perlin), expected);
+        let expected = simplex(p).abs() + simplex(p * 3.0).abs() * 0.25 + simplex(p * 9.0).abs() * 0.0625;
+        assert_eq!(turbulence(p, 3, 3.0, 0.25, simplex), expected);
+
+        // With a linear noise every octave adds lacunarity * gain times the
+        // one before.
+        let q = vec2::new(1.0f64, 0.0);
+        assert_eq!(fbm(q, 4, 2.0, 0.5, |p| p.x), 4.0);
+        assert_eq!(fbm(q, 3, 2.0, 0.25, |p| p.x), 1.75);
+        assert_eq!(turbulence(q, 3, 2.0, 0.25, |p| -p.x), 1.75);
+        assert_eq!(fbm(q, 3, 2.0, 0.25, |p| -p.x), -1.75);
+    }
+}
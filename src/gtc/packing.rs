@@ -0,0 +1,493 @@
+use core::ffi::{c_int, c_uint};
+
+use crate::{func_packing, ivec4, uvec4, vec3, vec4, Cast, Float, Integer, Number, SignedNumber, Vector};
+
+#[inline]
+pub fn pack_unorm<U, T, const N: usize>(v: Vector<T, N>) -> Vector<U, N>
+where
+    U: Integer + Cast<T>,
+    T: Float + Cast<U>,
+{
+    let max: T = U::MAX.cast();
+    v.map(|x| Float::round(clamp(x, T::ZERO, T::ONE) * max).cast())
+}
+
+#[inline]
+pub fn unpack_unorm<T, U, const N: usize>(v: Vector<U, N>) -> Vector<T, N>
+where
+    U: Integer + Cast<T>,
+    T: Float,
+{
+    let max: T = U::MAX.cast();
+    v.map(|x| x.cast() / max)
+}
+
+#[inline]
+pub fn pack_snorm<I, T, const N: usize>(v: Vector<T, N>) -> Vector<I, N>
+where
+    I: Integer + SignedNumber + Cast<T>,
+    T: Float + Cast<I>,
+{
+    let max: T = I::MAX.cast();
+    v.map(|x| Float::round(clamp(x, -T::ONE, T::ONE) * max).cast())
+}
+
+#[inline]
+pub fn unpack_snorm<T, I, const N: usize>(v: Vector<I, N>) -> Vector<T, N>
+where
+    I: Integer + SignedNumber + Cast<T>,
+    T: Float,
+{
+    let max: T = I::MAX.cast();
+    v.map(|x| clamp(x.cast() / max, -T::ONE, T::ONE))
+}
+
+#[inline]
+fn clamp<T: Number>(x: T, min: T, max: T) -> T {
+    // NaN falls through to `min`.
+    if x > max {
+        max
+    } else if x > min {
+        x
+    } else {
+        min
+    }
+}
+
+#[inline]
+pub const fn pack_i3x10_1x2(v: ivec4) -> u32 {
+    (v.0[0] as u32 & 0x3ff)
+        | ((v.0[1] as u32 & 0x3ff) << 10)
+        | ((v.0[2] as u32 & 0x3ff) << 20)
+        | ((v.0[3] as u32 & 0x3) << 30)
+}
+
+#[inline]
+pub const fn unpack_i3x10_1x2(p: u32) -> ivec4 {
+    ivec4::new(
+        ((p << 22) as i32 >> 22) as c_int,
+        ((p << 12) as i32 >> 22) as c_int,
+        ((p << 2) as i32 >> 22) as c_int,
+        (p as i32 >> 30) as c_int)
+}
+
+#[inline]
+pub const fn pack_u3x10_1x2(v: uvec4) -> u32 {
+    (v.0[0] & 0x3ff)
+        | ((v.0[1] & 0x3ff) << 10)
+        | ((v.0[2] & 0x3ff) << 20)
+        | ((v.0[3] & 0x3) << 30)
+}
+
+#[inline]
+pub const fn unpack_u3x10_1x2(p: u32) -> uvec4 {
+    uvec4::new(
+        (p & 0x3ff) as c_uint,
+        ((p >> 10) & 0x3ff) as c_uint,
+        ((p >> 20) & 0x3ff) as c_uint,
+        (p >> 30) as c_uint)
+}
+
+#[inline]
+pub fn pack_unorm_3x10_1x2(v: vec4) -> u32 {
+    (func_packing::unorm_to_bits(v.x, 1023.0) as u32)
+        | ((func_packing::unorm_to_bits(v.y, 1023.0) as u32) << 10)
+        | ((func_packing::unorm_to_bits(v.z, 1023.0) as u32) << 20)
+        | ((func_packing::unorm_to_bits(v.w, 3.0) as u32) << 30)
+}
+
+#[inline]
+pub fn unpack_unorm_3x10_1x2(p: u32) -> vec4 {
+    vec4::new(
+        (p & 0x3ff) as f32 / 1023.0,
+        ((p >> 10) & 0x3ff) as f32 / 1023.0,
+        ((p >> 20) & 0x3ff) as f32 / 1023.0,
+        (p >> 30) as f32 / 3.0)
+}
+
+#[inline]
+pub fn pack_snorm_3x10_1x2(v: vec4) -> u32 {
+    pack_i3x10_1x2(ivec4::new(
+        func_packing::snorm_to_bits(v.x, 511.0) as c_int,
+        func_packing::snorm_to_bits(v.y, 511.0) as c_int,
+        func_packing::snorm_to_bits(v.z, 511.0) as c_int,
+        func_packing::snorm_to_bits(v.w, 1.0) as c_int))
+}
+
+#[inline]
+pub fn unpack_snorm_3x10_1x2(p: u32) -> vec4 {
+    let v = unpack_i3x10_1x2(p);
+    vec4::new(
+        func_packing::bits_to_snorm(v.x as f32, 511.0),
+        func_packing::bits_to_snorm(v.y as f32, 511.0),
+        func_packing::bits_to_snorm(v.z as f32, 511.0),
+        func_packing::bits_to_snorm(v.w as f32, 1.0))
+}
+
+// Unsigned floats with a 5-bit exponent, as used by R11G11B10F. Negative
+// values pack to zero, the rest round to nearest even like `half`.
+const fn pack_ufloat(value: f32, mantissa_bits: u32) -> u32 {
+    let bits = value.to_bits();
+    let exponent = ((bits >> 23) & 0xff) as i32;
+    let mantissa = bits & 0x007f_ffff;
+    let shift = 23 - mantissa_bits;
+    let infinity = 0x1f << mantissa_bits;
+
+    if exponent == 0xff && mantissa != 0 {
+        return infinity | (1 << (mantissa_bits - 1)) | (mantissa >> shift);
+    }
+    if bits >> 31 != 0 {
+        return 0;
+    }
+    if exponent == 0xff {
+        return infinity;
+    }
+
+    let exponent = exponent - 127 + 15;
+    if exponent >= 0x1f {
+        return infinity;
+    }
+
+    if exponent <= 0 {
+        if exponent < -(mantissa_bits as i32) {
+            return 0;
+        }
+        let mantissa = mantissa | 0x0080_0000;
+        let shift = shift + (1 - exponent) as u32;
+        let result = mantissa >> shift;
+        let remainder = mantissa & ((1 << shift) - 1);
+        let halfway = 1 << (shift - 1);
+        let round = (remainder > halfway || (remainder == halfway && result & 1 == 1)) as u32;
+        return result + round;
+    }
+
+    let result = ((exponent as u32) << mantissa_bits) | (mantissa >> shift);
+    let remainder = mantissa & ((1 << shift) - 1);
+    let halfway = 1 << (shift - 1);
+    let round = (remainder > halfway || (remainder == halfway && result & 1 == 1)) as u32;
+    result + round
+}
+
+const fn unpack_ufloat(p: u32, mantissa_bits: u32) -> f32 {
+    let exponent = (p >> mantissa_bits) & 0x1f;
+    let mantissa = p & ((1 << mantissa_bits) - 1);
+    let shift = 23 - mantissa_bits;
+
+    if exponent == 0 {
+        // Subnormal: mantissa * 2^(-14 - mantissa_bits), both exact in f32.
+        mantissa as f32 * f32::from_bits((127 - 14 - mantissa_bits) << 23)
+    } else if exponent == 0x1f {
+        f32::from_bits(0x7f80_0000 | (mantissa << shift))
+    } else {
+        f32::from_bits(((exponent + 127 - 15) << 23) | (mantissa << shift))
+    }
+}
+
+#[inline]
+pub const fn pack_f2x11_1x10(v: vec3) -> u32 {
+    pack_ufloat(v.0[0], 6)
+        | (pack_ufloat(v.0[1], 6) << 11)
+        | (pack_ufloat(v.0[2], 5) << 22)
+}
+
+#[inline]
+pub const fn unpack_f2x11_1x10(p: u32) -> vec3 {
+    vec3::new(
+        unpack_ufloat(p & 0x7ff, 6),
+        unpack_ufloat((p >> 11) & 0x7ff, 6),
+        unpack_ufloat(p >> 22, 5))
+}
+
+// 2^exponent for the exponents RGB9E5 needs, built exactly from the bits.
+#[inline]
+const fn exp2i(exponent: i32) -> f32 {
+    f32::from_bits(((exponent + 127) as u32) << 23)
+}
+
+// Follows EXT_texture_shared_exponent, with 9-bit mantissas and a bias of 15.
+#[inline]
+pub fn pack_f3x9_e1x5(v: vec3) -> u32 {
+    const SHARED_EXP_MAX: f32 = 511.0 / 512.0 * 65536.0;
+
+    let color = v.map(|c| clamp(c, 0.0, SHARED_EXP_MAX));
+    let max_color = color.x.max(color.y).max(color.z);
+    let floor_log2 = if max_color < exp2i(-16) {
+        -16
+    } else {
+        ((max_color.to_bits() >> 23) as i32) - 127
+    };
+    let exp_shared = floor_log2 + 1 + 15;
+    let max_shared = Float::floor(max_color / exp2i(exp_shared - 15 - 9) + 0.5);
+    let exp_shared = if max_shared == 512.0 { exp_shared + 1 } else { exp_shared };
+    let scale = exp2i(exp_shared - 15 - 9);
+    let color = color.map(|c| Float::floor(c / scale + 0.5) as u32);
+
+    color.x | (color.y << 9) | (color.z << 18) | ((exp_shared as u32) << 27)
+}
+
+#[inline]
+pub const fn unpack_f3x9_e1x5(p: u32) -> vec3 {
+    let scale = exp2i((p >> 27) as i32 - 15 - 9);
+    vec3::new(
+        (p & 0x1ff) as f32 * scale,
+        ((p >> 9) & 0x1ff) as f32 * scale,
+        ((p >> 18) & 0x1ff) as f32 * scale)
+}
+
+#[inline]
+pub fn pack_rgbm(rgb: vec3) -> vec4 {
+    let color = rgb * (1.0 / 6.0);
+    let alpha = clamp(color.x.max(color.y).max(color.z.max(1e-6)), 0.0, 1.0);
+    let alpha = Float::ceil(alpha * 255.0) / 255.0;
+    let color = color / alpha;
+    vec4::new(color.x, color.y, color.z, alpha)
+}
+
+#[inline]
+pub fn unpack_rgbm(rgbm: vec4) -> vec3 {
+    vec3::new(rgbm.x, rgbm.y, rgbm.z) * (rgbm.w * 6.0)
+}
+
+#[inline]
+pub fn pack_unorm_1x5_1x6_1x5(v: vec3) -> u16 {
+    (func_packing::unorm_to_bits(v.x, 31.0) as u16)
+        | ((func_packing::unorm_to_bits(v.y, 63.0) as u16) << 5)
+        | ((func_packing::unorm_to_bits(v.z, 31.0) as u16) << 11)
+}
+
+#[inline]
+pub fn unpack_unorm_1x5_1x6_1x5(p: u16) -> vec3 {
+    vec3::new(
+        (p & 0x1f) as f32 / 31.0,
+        ((p >> 5) & 0x3f) as f32 / 63.0,
+        (p >> 11) as f32 / 31.0)
+}
+
+#[inline]
+pub fn pack_unorm_3x5_1x1(v: vec4) -> u16 {
+    (func_packing::unorm_to_bits(v.x, 31.0) as u16)
+        | ((func_packing::unorm_to_bits(v.y, 31.0) as u16) << 5)
+        | ((func_packing::unorm_to_bits(v.z, 31.0) as u16) << 10)
+        | ((func_packing::unorm_to_bits(v.w, 1.0) as u16) << 15)
+}
+
+#[inline]
+pub fn unpack_unorm_3x5_1x1(p: u16) -> vec4 {
+    vec4::new(
+        (p & 0x1f) as f32 / 31.0,
+        ((p >> 5) & 0x1f) as f32 / 31.0,
+        ((p >> 10) & 0x1f) as f32 / 31.0,
+        (p >> 15) as f32)
+}
+
+#[inline]
+pub fn pack_unorm_4x4(v: vec4) -> u16 {
+    (func_packing::unorm_to_bits(v.x, 15.0) as u16)
+        | ((func_packing::unorm_to_bits(v.y, 15.0) as u16) << 4)
+        | ((func_packing::unorm_to_bits(v.z, 15.0) as u16) << 8)
+        | ((func_packing::unorm_to_bits(v.w, 15.0) as u16) << 12)
+}
+
+#[inline]
+pub fn unpack_unorm_4x4(p: u16) -> vec4 {
+    vec4::new(
+        (p & 0xf) as f32 / 15.0,
+        ((p >> 4) & 0xf) as f32 / 15.0,
+        ((p >> 8) & 0xf) as f32 / 15.0,
+        (p >> 12) as f32 / 15.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        pack_f2x11_1x10, pack_f3x9_e1x5, pack_rgbm, pack_snorm, pack_snorm_3x10_1x2, pack_unorm,
+        pack_unorm_1x5_1x6_1x5, pack_unorm_3x10_1x2, pack_unorm_3x5_1x1, pack_unorm_4x4, unpack_f2x11_1x10,
+        unpack_f3x9_e1x5, unpack_rgbm, unpack_snorm, unpack_snorm_3x10_1x2, unpack_unorm, unpack_unorm_1x5_1x6_1x5,
+        unpack_unorm_3x10_1x2, unpack_unorm_3x5_1x1, unpack_unorm_4x4, vec1, vec3, vec4, Vector,
+    };
+
+    use super::{pack_ufloat, unpack_ufloat};
+
+    #[test]
+    fn generic_norm_round_trips() {
+        for p in 0..=u16::MAX {
+            let v: vec1 = unpack_unorm(Vector::from([p]));
+            assert_eq!(pack_unorm::<u16, f32, 1>(v).x, p);
+        }
+        for p in i16::MIN..=i16::MAX {
+            let v: vec1 = unpack_snorm(Vector::from([p]));
+            assert_eq!(pack_snorm::<i16, f32, 1>(v).x, p.max(-i16::MAX));
+        }
+        for p in 0..=u8::MAX {
+            let v: Vector<f64, 1> = unpack_unorm(Vector::from([p]));
+            assert_eq!(pack_unorm::<u8, f64, 1>(v).x, p);
+        }
+        for p in i8::MIN..=i8::MAX {
+            let v: Vector<f64, 1> = unpack_snorm(Vector::from([p]));
+            assert_eq!(pack_snorm::<i8, f64, 1>(v).x, p.max(-i8::MAX));
+        }
+    }
+
+    #[test]
+    fn generic_norm_error_is_half_a_step() {
+        for i in 0..=10000 {
+            let x = i as f32 / 10000.0;
+            let unorm: vec1 = unpack_unorm(pack_unorm::<u8, f32, 1>(vec1::new(x)));
+            assert!((unorm.x - x).abs() <= 0.5 / 255.0 + f32::EPSILON, "{x}");
+
+            let x = x * 2.0 - 1.0;
+            let snorm: vec1 = unpack_snorm(pack_snorm::<i8, f32, 1>(vec1::new(x)));
+            assert!((snorm.x - x).abs() <= 0.5 / 127.0 + f32::EPSILON, "{x}");
+        }
+        assert_eq!(pack_unorm::<u8, f32, 4>(vec4::new(-1.0, 2.0, f32::NAN, 0.5)), Vector::from([0, 255, 0, 128]));
+        assert_eq!(pack_snorm::<i8, f32, 4>(vec4::new(-2.0, 2.0, f32::NAN, -0.5)), Vector::from([-127, 127, -127, -64]));
+    }
+
+    #[test]
+    fn packed_3x10_1x2_round_trips() {
+        for i in 0..1024 {
+            let p = i | (i << 10) | (i << 20) | ((i & 3) << 30);
+            assert_eq!(pack_unorm_3x10_1x2(unpack_unorm_3x10_1x2(p)), p);
+
+            // -512 and -2 unpack to -1 like the values above them.
+            let (lane, last) = (if i == 512 { 513 } else { i }, if i & 3 == 2 { 3 } else { i & 3 });
+            let expected = lane | (lane << 10) | (lane << 20) | (last << 30);
+            assert_eq!(pack_snorm_3x10_1x2(unpack_snorm_3x10_1x2(p)), expected);
+        }
+    }
+
+    #[test]
+    fn packed_16_round_trips() {
+        for p in 0..=u16::MAX {
+            assert_eq!(pack_unorm_1x5_1x6_1x5(unpack_unorm_1x5_1x6_1x5(p)), p);
+            assert_eq!(pack_unorm_3x5_1x1(unpack_unorm_3x5_1x1(p)), p);
+            assert_eq!(pack_unorm_4x4(unpack_unorm_4x4(p)), p);
+        }
+    }
+
+    #[test]
+    fn packed_16_error_is_half_a_step() {
+        for i in 0..=1000 {
+            let x = i as f32 / 1000.0;
+            let v = unpack_unorm_1x5_1x6_1x5(pack_unorm_1x5_1x6_1x5(vec3::splat(x)));
+            assert!((v.x - x).abs() <= 0.5 / 31.0 + f32::EPSILON && (v.y - x).abs() <= 0.5 / 63.0 + f32::EPSILON, "{x}");
+            let v = unpack_unorm_3x5_1x1(pack_unorm_3x5_1x1(vec4::splat(x)));
+            assert!((v.x - x).abs() <= 0.5 / 31.0 + f32::EPSILON && (v.w - x).abs() <= 0.5, "{x}");
+            let v = unpack_unorm_4x4(pack_unorm_4x4(vec4::splat(x)));
+            assert!((v.x - x).abs() <= 0.5 / 15.0 + f32::EPSILON, "{x}");
+        }
+        assert_eq!(pack_unorm_1x5_1x6_1x5(vec3::new(-1.0, 2.0, f32::NAN)), 0x07e0);
+        assert_eq!(pack_unorm_4x4(vec4::new(2.0, -1.0, 1.0, 0.0)), 0x0f0f);
+    }
+
+    #[test]
+    fn f2x11_1x10_round_trips() {
+        for p in 0..0x800 {
+            let (x, z) = (unpack_ufloat(p, 6), unpack_ufloat(p & 0x3ff, 5));
+            if x.is_nan() {
+                assert!(unpack_ufloat(pack_ufloat(x, 6), 6).is_nan(), "{p:#x}");
+            } else {
+                assert_eq!(pack_ufloat(x, 6), p);
+            }
+            if z.is_nan() {
+                assert!(unpack_ufloat(pack_ufloat(z, 5), 5).is_nan(), "{p:#x}");
+            } else {
+                assert_eq!(pack_ufloat(z, 5), p & 0x3ff);
+            }
+        }
+        let p = 0x3c0 | (0x7bf << 11) | (0x001 << 22);
+        assert_eq!(pack_f2x11_1x10(unpack_f2x11_1x10(p)), p);
+    }
+
+    #[test]
+    fn f2x11_1x10_error() {
+        // Half an ulp of the mantissa for normal values, and half the
+        // smallest subnormal below them.
+        for bits in (0x3380_0000..0x477c_0000u32).step_by(997) {
+            let x = f32::from_bits(bits);
+            let v = unpack_f2x11_1x10(pack_f2x11_1x10(vec3::splat(x)));
+            assert!((v.x - x).abs() <= (x * 2f32.powi(-7)).max(2f32.powi(-21)), "{x}");
+            assert!((v.z - x).abs() <= (x * 2f32.powi(-6)).max(2f32.powi(-20)), "{x}");
+        }
+    }
+
+    #[test]
+    fn ufloat_edge_cases() {
+        assert_eq!(pack_ufloat(0.0, 6), 0);
+        assert_eq!(pack_ufloat(-0.0, 6), 0);
+        assert_eq!(pack_ufloat(-1.0, 6), 0);
+        assert_eq!(pack_ufloat(f32::NEG_INFINITY, 6), 0);
+        assert_eq!(pack_ufloat(f32::INFINITY, 6), 0x7c0);
+        assert_eq!(pack_ufloat(f32::INFINITY, 5), 0x3e0);
+        assert!(unpack_ufloat(pack_ufloat(f32::NAN, 6), 6).is_nan());
+        assert!(unpack_ufloat(pack_ufloat(-f32::NAN, 5), 5).is_nan());
+        assert!(unpack_ufloat(pack_ufloat(f32::from_bits(0x7f80_0001), 6), 6).is_nan());
+
+        // The largest finite values, and overflow past their midpoint with
+        // infinity.
+        assert_eq!(pack_ufloat(65024.0, 6), 0x7bf);
+        assert_eq!(pack_ufloat(65279.0, 6), 0x7bf);
+        assert_eq!(pack_ufloat(65280.0, 6), 0x7c0);
+        assert_eq!(pack_ufloat(1e10, 6), 0x7c0);
+        assert_eq!(pack_ufloat(64512.0, 5), 0x3df);
+        assert_eq!(pack_ufloat(65536.0, 5), 0x3e0);
+
+        // Subnormals, with ties to even, and f32 subnormals flush to zero.
+        assert_eq!(pack_ufloat(2f32.powi(-20), 6), 0x001);
+        assert_eq!(pack_ufloat(2f32.powi(-21), 6), 0x000);
+        assert_eq!(pack_ufloat(3.0 * 2f32.powi(-21), 6), 0x002);
+        assert_eq!(pack_ufloat(2f32.powi(-14), 6), 0x040);
+        assert_eq!(unpack_ufloat(0x001, 6), 2f32.powi(-20));
+        assert_eq!(unpack_ufloat(0x001, 5), 2f32.powi(-19));
+        assert_eq!(pack_ufloat(f32::from_bits(1), 6), 0);
+        assert_eq!(pack_ufloat(f32::from_bits(0x007f_ffff), 5), 0);
+    }
+
+    #[test]
+    fn f3x9_e1x5_round_trips() {
+        // Several packings decode to the same color, and packing the color
+        // again picks one of them that decodes to it exactly.
+        for p in (0..u32::MAX).step_by(4001) {
+            let color = unpack_f3x9_e1x5(p);
+            let q = pack_f3x9_e1x5(color);
+            assert_eq!(unpack_f3x9_e1x5(q), color, "{p:#x}");
+            assert_eq!(pack_f3x9_e1x5(unpack_f3x9_e1x5(q)), q, "{p:#x}");
+        }
+        assert_eq!(pack_f3x9_e1x5(vec3::splat(0.0)), 0);
+        assert_eq!(pack_f3x9_e1x5(vec3::new(-1.0, f32::NAN, 1e10)), 0x1ff << 18 | 31 << 27);
+    }
+
+    #[test]
+    fn f3x9_e1x5_error() {
+        // Half a step of the shared exponent, which is at most 2^-8 of the
+        // largest component.
+        for i in 0..20000 {
+            let t = i as f32 / 20000.0;
+            let color = vec3::new(t, 1.0 - t, t * t) * 2f32.powf(t * 40.0 - 24.0);
+            let max_color = color.x.max(color.y).max(color.z);
+            let v = unpack_f3x9_e1x5(pack_f3x9_e1x5(color));
+            for (a, b) in v.0.into_iter().zip(color.0) {
+                assert!((a - b).abs() <= (max_color * 2f32.powi(-8)).max(2f32.powi(-25)), "{color:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn rgbm_round_trips() {
+        for i in 0..=6000 {
+            let t = i as f32 / 1000.0;
+            let rgb = vec3::new(t, t * 0.5, 6.0 - t);
+            let rgbm = pack_rgbm(rgb);
+            assert!(rgbm.0.iter().all(|c| (0.0..=1.0).contains(c)), "{rgb:?}");
+            assert_eq!(rgbm.w * 255.0, (rgbm.w * 255.0).round(), "{rgb:?}");
+            let v = unpack_rgbm(rgbm);
+            for (a, b) in v.0.into_iter().zip(rgb.0) {
+                assert!((a - b).abs() <= 6.0 * 4.0 * f32::EPSILON, "{rgb:?}");
+            }
+            // The multiplier may land on the other side of a step when packed
+            // again, but the color stays the same.
+            let w = unpack_rgbm(pack_rgbm(v));
+            assert!((w - v).0.iter().all(|c| c.abs() <= 6.0 * 4.0 * f32::EPSILON), "{rgb:?}");
+        }
+    }
+}
@@ -0,0 +1,291 @@
+use crate::{quat, two_pi, vec2, vec3, Float, FloatConstants, Vector};
+
+pub trait Rng {
+    fn next_u32(&mut self) -> u32;
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        (self.next_u32() as u64) | ((self.next_u32() as u64) << 32)
+    }
+}
+
+// Any rand_core generator, as in `RandCore(rand::rng())`.
+#[cfg(feature = "rand_core")]
+#[derive(Clone, Debug, Default)]
+pub struct RandCore<R>(pub R);
+
+#[cfg(feature = "rand_core")]
+impl<R: rand_core::RngCore> Rng for RandCore<R> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+}
+
+// SplitMix64, after Steele, Lea and Flood: the same sequence for a seed on
+// every platform, for tests and procedural content. Not for cryptography.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    #[inline]
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+}
+
+impl Rng for SplitMix64 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let z = self.state;
+        let z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        let z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+pub trait RandomFloat: Float + FloatConstants {
+    // Uniform in [0, 1).
+    fn unit_rand<R: Rng + ?Sized>(rng: &mut R) -> Self;
+}
+
+impl RandomFloat for f32 {
+    #[inline]
+    fn unit_rand<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        (rng.next_u32() >> 8) as f32 * (1.0 / (1u32 << 24) as f32)
+    }
+}
+
+impl RandomFloat for f64 {
+    #[inline]
+    fn unit_rand<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        (rng.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+}
+
+#[inline]
+pub fn linear_rand<T: RandomFloat, R: Rng + ?Sized>(rng: &mut R, min: T, max: T) -> T {
+    min + (max - min) * T::unit_rand(rng)
+}
+
+// Marsaglia's polar method.
+#[inline]
+pub fn gauss_rand<T: RandomFloat, R: Rng + ?Sized>(rng: &mut R, mean: T, deviation: T) -> T {
+    let two = T::ONE + T::ONE;
+    loop {
+        let x1 = linear_rand(rng, -T::ONE, T::ONE);
+        let x2 = linear_rand(rng, -T::ONE, T::ONE);
+        let w = x1 * x1 + x2 * x2;
+        if w > T::ZERO && w < T::ONE {
+            return x2 * deviation * (-two * w.ln() / w).sqrt() + mean;
+        }
+    }
+}
+
+impl<T: RandomFloat, const N: usize> Vector<T, N> {
+    #[inline]
+    pub fn linear_rand<R: Rng + ?Sized>(rng: &mut R, min: Self, max: Self) -> Self {
+        let mut result = min;
+        for i in 0..N {
+            result.0[i] = linear_rand(rng, min.0[i], max.0[i]);
+        }
+        result
+    }
+
+    #[inline]
+    pub fn gauss_rand<R: Rng + ?Sized>(rng: &mut R, mean: Self, deviation: Self) -> Self {
+        let mut result = mean;
+        for i in 0..N {
+            result.0[i] = gauss_rand(rng, mean.0[i], deviation.0[i]);
+        }
+        result
+    }
+}
+
+#[inline]
+pub fn circular_rand<T: RandomFloat, R: Rng + ?Sized>(rng: &mut R, radius: T) -> vec2<T> {
+    let a = linear_rand(rng, T::ZERO, two_pi());
+    vec2::new(a.cos(), a.sin()) * radius
+}
+
+#[inline]
+pub fn spherical_rand<T: RandomFloat, R: Rng + ?Sized>(rng: &mut R, radius: T) -> vec3<T> {
+    let z = linear_rand(rng, -T::ONE, T::ONE);
+    let a = linear_rand(rng, T::ZERO, two_pi());
+    let r = (T::ONE - z * z).sqrt();
+    vec3::new(r * a.cos(), r * a.sin(), z) * radius
+}
+
+// Rejection sampling never accepts a point for a NaN or infinite radius.
+#[inline]
+fn assert_radius<T: RandomFloat>(radius: T) {
+    assert!(radius >= T::ZERO && radius < T::INFINITY, "radius must be finite and non-negative");
+}
+
+#[inline]
+pub fn disk_rand<T: RandomFloat, R: Rng + ?Sized>(rng: &mut R, radius: T) -> vec2<T> {
+    assert_radius(radius);
+    loop {
+        let result = vec2::linear_rand(rng, vec2::splat(-radius), vec2::splat(radius));
+        if result.dot(result) <= radius * radius {
+            return result;
+        }
+    }
+}
+
+#[inline]
+pub fn ball_rand<T: RandomFloat, R: Rng + ?Sized>(rng: &mut R, radius: T) -> vec3<T> {
+    assert_radius(radius);
+    loop {
+        let result = vec3::linear_rand(rng, vec3::splat(-radius), vec3::splat(radius));
+        if result.dot(result) <= radius * radius {
+            return result;
+        }
+    }
+}
+
+// Uniform over the triangle a, b, c.
+#[inline]
+pub fn triangle_rand<T: RandomFloat, R: Rng + ?Sized, const N: usize>(
+    rng: &mut R,
+    a: Vector<T, N>,
+    b: Vector<T, N>,
+    c: Vector<T, N>,
+) -> Vector<T, N> {
+    let u = T::unit_rand(rng);
+    let v = T::unit_rand(rng);
+    let (u, v) = if u + v > T::ONE { (T::ONE - u, T::ONE - v) } else { (u, v) };
+    a + (b - a) * u + (c - a) * v
+}
+
+// Uniform over the unit hemisphere around +z.
+#[inline]
+pub fn hemisphere_rand<T: RandomFloat, R: Rng + ?Sized>(rng: &mut R) -> vec3<T> {
+    let z = T::unit_rand(rng);
+    let a = linear_rand(rng, T::ZERO, two_pi());
+    let r = (T::ONE - z * z).sqrt();
+    vec3::new(r * a.cos(), r * a.sin(), z)
+}
+
+// Cosine-weighted over the unit hemisphere around +z.
+#[inline]
+pub fn cosine_hemisphere_rand<T: RandomFloat, R: Rng + ?Sized>(rng: &mut R) -> vec3<T> {
+    let u = T::unit_rand(rng);
+    let a = linear_rand(rng, T::ZERO, two_pi());
+    let r = u.sqrt();
+    vec3::new(r * a.cos(), r * a.sin(), (T::ONE - u).sqrt())
+}
+
+// Uniform unit quaternion, after Shoemake.
+#[inline]
+pub fn quat_rand<T: RandomFloat, R: Rng + ?Sized>(rng: &mut R) -> quat<T> {
+    let u = T::unit_rand(rng);
+    let a = linear_rand(rng, T::ZERO, two_pi());
+    let b = linear_rand(rng, T::ZERO, two_pi());
+    let r1 = (T::ONE - u).sqrt();
+    let r2 = u.sqrt();
+    quat::new(r1 * a.sin(), r1 * a.cos(), r2 * b.sin(), r2 * b.cos())
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "rand_core")]
+    use crate::RandCore;
+    use crate::{ball_rand, disk_rand, gauss_rand, linear_rand, quat_rand, spherical_rand, vec3, Rng, SplitMix64, Vector};
+
+    #[test]
+    fn split_mix_64_sequence() {
+        let mut rng = SplitMix64::new(0);
+        assert_eq!(rng.next_u64(), 0xe220_a839_7b1d_cdaf);
+        assert_eq!(rng.next_u64(), 0x6e78_9e6a_a1b9_65f4);
+        assert_eq!(rng.next_u64(), 0x06c4_5d18_8009_454f);
+        assert_eq!(rng.next_u32(), 0xf88b_b8a8);
+    }
+
+    // Exact where only arithmetic is involved, and within a few ulps where
+    // ln, sin and cos may come from libm.
+    #[test]
+    fn seeded_output() {
+        let mut rng = SplitMix64::new(42);
+        assert_eq!(linear_rand(&mut rng, -1.0f32, 1.0).to_bits(), 0x3ef7_5cc8);
+        assert_eq!(linear_rand(&mut rng, -1.0f64, 1.0).to_bits(), 0xbfe5_c407_3313_6644);
+        let close = |a: f32, b: f32| (a - b).abs() <= 1e-6;
+        let g = gauss_rand(&mut rng, 0.0f32, 1.0);
+        assert!(close(g, -0.90155727), "{g}");
+        let v = vec3::linear_rand(&mut rng, vec3::splat(0.0), vec3::splat(10.0));
+        assert_eq!(v.map(f32::to_bits), Vector::from([0x3ec2_b6e0, 0x410a_ea9f, 0x400b_c782]));
+
+        let v = spherical_rand(&mut rng, 1.0f32);
+        assert!(close(v.x, -0.42786026) && close(v.y, 0.67484635) && close(v.z, 0.60126364), "{v:?}");
+        let q = quat_rand::<f32, _>(&mut rng);
+        assert!(close(q.x, 0.5930395) && close(q.y, 0.17269067) && close(q.z, 0.03463142) && close(q.w, -0.78567344), "{q:?}");
+    }
+
+    #[test]
+    fn disks_and_balls_stay_inside() {
+        let mut rng = SplitMix64::new(7);
+        for radius in [0.0f64, 1e-3, 1.0, 1e3] {
+            for _ in 0..100 {
+                assert!(disk_rand(&mut rng, radius).length() <= radius);
+                assert!(ball_rand(&mut rng, radius).length() <= radius);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn disk_rand_rejects_nan() {
+        disk_rand(&mut SplitMix64::new(0), f32::NAN);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ball_rand_rejects_infinity() {
+        ball_rand(&mut SplitMix64::new(0), f64::INFINITY);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ball_rand_rejects_negative_radii() {
+        ball_rand(&mut SplitMix64::new(0), -1.0f32);
+    }
+
+    #[cfg(feature = "rand_core")]
+    #[test]
+    fn rand_core_adapter() {
+        struct Counter(u64);
+
+        impl rand_core::RngCore for Counter {
+            fn next_u32(&mut self) -> u32 {
+                self.next_u64() as u32
+            }
+
+            fn next_u64(&mut self) -> u64 {
+                self.0 += 1;
+                self.0
+            }
+
+            fn fill_bytes(&mut self, dst: &mut [u8]) {
+                rand_core::impls::fill_bytes_via_next(self, dst)
+            }
+        }
+
+        let mut rng = RandCore(Counter(0));
+        assert_eq!(Rng::next_u32(&mut rng), 1);
+        assert_eq!(Rng::next_u64(&mut rng), 2);
+        assert_eq!(linear_rand(&mut rng, 0.0f64, 1.0), (3u64 >> 11) as f64 / (1u64 << 53) as f64);
+    }
+}
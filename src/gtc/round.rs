@@ -0,0 +1,67 @@
+use crate::{ext::scalar_integer::{power_of_two, with_magnitude}, Integer, Vector};
+
+// Ties round towards the smaller power of two.
+#[inline]
+pub fn round_power_of_two<T: Integer>(value: T) -> T {
+    with_magnitude(value, |value| {
+        if value == T::ZERO || value.count_ones() == 1 {
+            return value;
+        }
+        let exponent = T::BITS - 1 - value.leading_zeros();
+        let prev = T::ONE << exponent;
+        let below = value - prev;
+        let above = prev - below;
+        if above < below { power_of_two(exponent + 1) } else { prev }
+    })
+}
+
+impl<T: Integer, const N: usize> Vector<T, N> {
+    #[inline]
+    pub fn round_power_of_two(self) -> Self {
+        self.map(round_power_of_two)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ivec2, round_power_of_two};
+
+    #[test]
+    fn rounds_to_the_nearest_power_of_two() {
+        assert_eq!(round_power_of_two(0u32), 0);
+        assert_eq!(round_power_of_two(1u32), 1);
+        assert_eq!(round_power_of_two(2u32), 2);
+        assert_eq!(round_power_of_two(3u32), 2);
+        assert_eq!(round_power_of_two(5u32), 4);
+        assert_eq!(round_power_of_two(6u32), 4);
+        assert_eq!(round_power_of_two(7u32), 8);
+        assert_eq!(round_power_of_two(1000u32), 1024);
+        assert_eq!(round_power_of_two(0i32), 0);
+    }
+
+    #[test]
+    fn negatives_round_their_magnitude() {
+        assert_eq!(round_power_of_two(-1i32), -1);
+        assert_eq!(round_power_of_two(-3i32), -2);
+        assert_eq!(round_power_of_two(-7i32), -8);
+        assert_eq!(round_power_of_two(-1000i64), -1024);
+        assert_eq!(round_power_of_two(i32::MIN), i32::MIN);
+        assert_eq!(round_power_of_two(i32::MIN + 1), i32::MIN);
+        assert_eq!(round_power_of_two(-100i8), -128);
+        assert_eq!(ivec2::new(-5, 13).round_power_of_two(), ivec2::new(-4, 16));
+    }
+
+    // Powers of two beyond the type wrap like power_of_two does.
+    #[test]
+    fn values_near_max() {
+        assert_eq!(round_power_of_two(0xc000_0000u32), 0x8000_0000);
+        assert_eq!(round_power_of_two(0xc000_0001u32), 0);
+        assert_eq!(round_power_of_two(u32::MAX), 0);
+        assert_eq!(round_power_of_two(0x8000_0000u32), 0x8000_0000);
+        assert_eq!(round_power_of_two(192u8), 128);
+        assert_eq!(round_power_of_two(u8::MAX), 0);
+        assert_eq!(round_power_of_two(0x3000_0000i32), 0x2000_0000);
+        assert_eq!(round_power_of_two(i32::MAX), i32::MIN);
+        assert_eq!(round_power_of_two(u64::MAX >> 1), 1 << 63);
+    }
+}
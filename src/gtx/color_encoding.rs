@@ -0,0 +1,94 @@
+use crate::{gtc::color_space::map_rgb, vec3, Color, Float};
+
+// sRGB primaries and Bradford chromatic adaptation between the D65 and D50
+// white points, as tabulated by Bruce Lindbloom.
+
+#[inline]
+pub(crate) fn transform<C: Color>(color: C, rows: [[f64; 3]; 3]) -> C {
+    map_rgb(color, |v| {
+        let row = |r: [f64; 3]| v.dot(vec3::new(C::Scalar::from_f64(r[0]), C::Scalar::from_f64(r[1]), C::Scalar::from_f64(r[2])));
+        vec3::new(row(rows[0]), row(rows[1]), row(rows[2]))
+    })
+}
+
+#[inline]
+pub fn convert_linear_srgb_to_d65_xyz<C: Color>(color: C) -> C {
+    transform(color, [
+        [0.4124564, 0.3575761, 0.1804375],
+        [0.2126729, 0.7151522, 0.0721750],
+        [0.0193339, 0.1191920, 0.9503041],
+    ])
+}
+
+#[inline]
+pub fn convert_linear_srgb_to_d50_xyz<C: Color>(color: C) -> C {
+    transform(color, [
+        [0.4360747, 0.3850649, 0.1430804],
+        [0.2225045, 0.7168786, 0.0606169],
+        [0.0139322, 0.0971045, 0.7141733],
+    ])
+}
+
+#[inline]
+pub fn convert_d65_xyz_to_linear_srgb<C: Color>(color: C) -> C {
+    transform(color, [
+        [3.2404542, -1.5371385, -0.4985314],
+        [-0.9692660, 1.8760108, 0.0415560],
+        [0.0556434, -0.2040259, 1.0572252],
+    ])
+}
+
+#[inline]
+pub fn convert_d50_xyz_to_linear_srgb<C: Color>(color: C) -> C {
+    transform(color, [
+        [3.1338561, -1.6168667, -0.4906146],
+        [-0.9787684, 1.9161415, 0.0334540],
+        [0.0719453, -0.2289914, 1.4052427],
+    ])
+}
+
+#[inline]
+pub fn convert_d65_xyz_to_d50_xyz<C: Color>(color: C) -> C {
+    transform(color, [
+        [1.0478112, 0.0228866, -0.0501270],
+        [0.0295424, 0.9904844, -0.0170491],
+        [-0.0092345, 0.0150436, 0.7521316],
+    ])
+}
+
+#[inline]
+pub fn convert_d50_xyz_to_d65_xyz<C: Color>(color: C) -> C {
+    transform(color, [
+        [0.9555766, -0.0230393, 0.0631636],
+        [-0.0282895, 1.0099416, 0.0210077],
+        [0.0122982, -0.0204830, 1.3299098],
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{convert_d50_xyz_to_d65_xyz, convert_d50_xyz_to_linear_srgb, convert_d65_xyz_to_d50_xyz, convert_d65_xyz_to_linear_srgb, convert_linear_srgb_to_d50_xyz, convert_linear_srgb_to_d65_xyz, vec3};
+
+    fn close(a: vec3<f64>, b: vec3<f64>, tolerance: f64) -> bool {
+        (a - b).0.iter().all(|d| d.abs() < tolerance)
+    }
+
+    #[test]
+    fn white_points() {
+        let white = vec3::splat(1.0);
+        assert!(close(convert_linear_srgb_to_d65_xyz(white), vec3::new(0.95047, 1.0, 1.08883), 1e-5));
+        assert!(close(convert_linear_srgb_to_d50_xyz(white), vec3::new(0.96422, 1.0, 0.82521), 1e-5));
+        assert!(close(convert_d65_xyz_to_d50_xyz(vec3::new(0.95047, 1.0, 1.08883)), vec3::new(0.96422, 1.0, 0.82521), 1e-5));
+        assert!(close(convert_linear_srgb_to_d65_xyz(vec3::new(1.0, 0.0, 0.0)), vec3::new(0.4124564, 0.2126729, 0.0193339), 1e-12));
+    }
+
+    #[test]
+    fn round_trips() {
+        for i in 0..5 * 5 * 5 {
+            let rgb = vec3::new((i % 5) as f64, (i / 5 % 5) as f64, (i / 25) as f64) / 4.0;
+            assert!(close(convert_d65_xyz_to_linear_srgb(convert_linear_srgb_to_d65_xyz(rgb)), rgb, 1e-6));
+            assert!(close(convert_d50_xyz_to_linear_srgb(convert_linear_srgb_to_d50_xyz(rgb)), rgb, 1e-6));
+            assert!(close(convert_d50_xyz_to_d65_xyz(convert_d65_xyz_to_d50_xyz(rgb)), rgb, 1e-6));
+        }
+    }
+}
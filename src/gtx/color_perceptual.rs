@@ -0,0 +1,320 @@
+use core::{f64::consts::PI, ops::{Add, Mul}};
+
+use crate::{
+    convert_d50_xyz_to_linear_srgb, convert_linear_srgb_to_d50_xyz, gtc::color_space::map_rgb,
+    gtx::color_encoding::transform, vec3, Color, Float, Number,
+};
+
+// Oklab after Björn Ottosson, CIELAB relative to the D50 white point as in
+// ICC profiles and CSS. Lightness is in [0, 1] for Oklab and [0, 100] for
+// CIELAB, hues are in degrees.
+
+#[inline]
+fn cbrt<T: Float>(x: T) -> T {
+    let r = x.abs().powf(T::ONE / T::from_f64(3.0));
+    if x < T::ZERO { -r } else { r }
+}
+
+#[inline]
+fn to_degrees<T: Float>(x: T) -> T {
+    x * T::from_f64(180.0 / PI)
+}
+
+#[inline]
+fn to_radians<T: Float>(x: T) -> T {
+    x * T::from_f64(PI / 180.0)
+}
+
+// Lightness, chroma and hue in [0, 360) from a lightness and two opponent
+// axes.
+#[inline]
+fn to_polar<T: Float>(v: vec3<T>) -> vec3<T> {
+    let h = to_degrees(v.z.atan2(v.y));
+    let h = if h < T::ZERO { h + T::from_f64(360.0) } else { h };
+    vec3::new(v.x, (v.y * v.y + v.z * v.z).sqrt(), h)
+}
+
+#[inline]
+fn from_polar<T: Float>(v: vec3<T>) -> vec3<T> {
+    let h = to_radians(v.z);
+    vec3::new(v.x, v.y * h.cos(), v.y * h.sin())
+}
+
+#[inline]
+pub fn convert_linear_srgb_to_oklab<C: Color>(color: C) -> C {
+    let lms = transform(color, [
+        [0.4122214708, 0.5363325363, 0.0514459929],
+        [0.2119034982, 0.6806995451, 0.1073969566],
+        [0.0883024619, 0.2817188376, 0.6299787005],
+    ]);
+    transform(map_rgb(lms, |lms| lms.map(cbrt)), [
+        [0.2104542553, 0.7936177850, -0.0040720468],
+        [1.9779984951, -2.4285922050, 0.4505937099],
+        [0.0259040371, 0.7827717662, -0.8086757660],
+    ])
+}
+
+#[inline]
+pub fn convert_oklab_to_linear_srgb<C: Color>(color: C) -> C {
+    let lms = transform(color, [
+        [1.0, 0.3963377774, 0.2158037573],
+        [1.0, -0.1055613458, -0.0638541728],
+        [1.0, -0.0894841775, -1.2914855480],
+    ]);
+    transform(map_rgb(lms, |lms| lms * lms * lms), [
+        [4.0767416621, -3.3077115913, 0.2309699292],
+        [-1.2684380046, 2.6097574011, -0.3413193965],
+        [-0.0041960863, -0.7034186147, 1.7076147010],
+    ])
+}
+
+#[inline]
+pub fn convert_oklab_to_oklch<C: Color>(color: C) -> C {
+    map_rgb(color, to_polar)
+}
+
+#[inline]
+pub fn convert_oklch_to_oklab<C: Color>(color: C) -> C {
+    map_rgb(color, from_polar)
+}
+
+#[inline]
+pub fn convert_linear_srgb_to_oklch<C: Color>(color: C) -> C {
+    convert_oklab_to_oklch(convert_linear_srgb_to_oklab(color))
+}
+
+#[inline]
+pub fn convert_oklch_to_linear_srgb<C: Color>(color: C) -> C {
+    convert_oklab_to_linear_srgb(convert_oklch_to_oklab(color))
+}
+
+#[inline]
+fn d50_white<T: Float>() -> vec3<T> {
+    vec3::new(T::from_f64(0.96422), T::ONE, T::from_f64(0.82521))
+}
+
+#[inline]
+pub fn convert_d50_xyz_to_lab<C: Color>(color: C) -> C {
+    map_rgb(color, |xyz| {
+        let epsilon = C::Scalar::from_f64(216.0 / 24389.0);
+        let kappa = C::Scalar::from_f64(24389.0 / 27.0);
+        let f = (xyz / d50_white()).map(|t| {
+            if t > epsilon { cbrt(t) } else { (kappa * t + C::Scalar::from_f64(16.0)) / C::Scalar::from_f64(116.0) }
+        });
+        vec3::new(
+            C::Scalar::from_f64(116.0) * f.y - C::Scalar::from_f64(16.0),
+            C::Scalar::from_f64(500.0) * (f.x - f.y),
+            C::Scalar::from_f64(200.0) * (f.y - f.z))
+    })
+}
+
+#[inline]
+pub fn convert_lab_to_d50_xyz<C: Color>(color: C) -> C {
+    map_rgb(color, |lab| {
+        let epsilon = C::Scalar::from_f64(6.0 / 29.0);
+        let kappa = C::Scalar::from_f64(24389.0 / 27.0);
+        let fy = (lab.x + C::Scalar::from_f64(16.0)) / C::Scalar::from_f64(116.0);
+        let f = vec3::new(fy + lab.y / C::Scalar::from_f64(500.0), fy, fy - lab.z / C::Scalar::from_f64(200.0));
+        let t = f.map(|f| {
+            if f > epsilon { f * f * f } else { (C::Scalar::from_f64(116.0) * f - C::Scalar::from_f64(16.0)) / kappa }
+        });
+        t * d50_white()
+    })
+}
+
+#[inline]
+pub fn convert_linear_srgb_to_lab<C: Color>(color: C) -> C {
+    convert_d50_xyz_to_lab(convert_linear_srgb_to_d50_xyz(color))
+}
+
+#[inline]
+pub fn convert_lab_to_linear_srgb<C: Color>(color: C) -> C {
+    convert_d50_xyz_to_linear_srgb(convert_lab_to_d50_xyz(color))
+}
+
+#[inline]
+pub fn convert_lab_to_lch<C: Color>(color: C) -> C {
+    map_rgb(color, to_polar)
+}
+
+#[inline]
+pub fn convert_lch_to_lab<C: Color>(color: C) -> C {
+    map_rgb(color, from_polar)
+}
+
+// CIE76: the euclidean distance between two CIELAB colors.
+#[inline]
+pub fn delta_e_76<C: Color>(x: C, y: C) -> C::Scalar {
+    x.rgb().distance(y.rgb())
+}
+
+// CIEDE2000 with unit weighting factors, following Sharma, Wu and Dalal.
+#[inline]
+pub fn delta_e_2000<C: Color>(x: C, y: C) -> C::Scalar {
+    let (x, y) = (x.rgb(), y.rgb());
+    let c = |v: f64| C::Scalar::from_f64(v);
+    let pow7 = |v: C::Scalar| {
+        let v3 = v * v * v;
+        v3 * v3 * v
+    };
+    let twenty_five_7 = c(6103515625.0);
+    let hue = |b: C::Scalar, a: C::Scalar| {
+        if a == C::Scalar::ZERO && b == C::Scalar::ZERO {
+            return C::Scalar::ZERO;
+        }
+        let h = to_degrees(b.atan2(a));
+        if h < C::Scalar::ZERO { h + c(360.0) } else { h }
+    };
+
+    let c_bar = ((x.y * x.y + x.z * x.z).sqrt() + (y.y * y.y + y.z * y.z).sqrt()) / c(2.0);
+    let g = c(0.5) * (C::Scalar::ONE - (pow7(c_bar) / (pow7(c_bar) + twenty_five_7)).sqrt());
+    let a1 = x.y * (C::Scalar::ONE + g);
+    let a2 = y.y * (C::Scalar::ONE + g);
+    let c1 = (a1 * a1 + x.z * x.z).sqrt();
+    let c2 = (a2 * a2 + y.z * y.z).sqrt();
+    let h1 = hue(x.z, a1);
+    let h2 = hue(y.z, a2);
+
+    let delta_l = y.x - x.x;
+    let delta_c = c2 - c1;
+    let chroma_product = c1 * c2;
+    let delta_h = if chroma_product == C::Scalar::ZERO {
+        C::Scalar::ZERO
+    } else if (h2 - h1).abs() <= c(180.0) {
+        h2 - h1
+    } else if h2 - h1 > c(180.0) {
+        h2 - h1 - c(360.0)
+    } else {
+        h2 - h1 + c(360.0)
+    };
+    let delta_h = c(2.0) * chroma_product.sqrt() * to_radians(delta_h / c(2.0)).sin();
+
+    let l_bar = (x.x + y.x) / c(2.0);
+    let c_bar = (c1 + c2) / c(2.0);
+    let h_bar = if chroma_product == C::Scalar::ZERO {
+        h1 + h2
+    } else if (h1 - h2).abs() <= c(180.0) {
+        (h1 + h2) / c(2.0)
+    } else if h1 + h2 < c(360.0) {
+        (h1 + h2 + c(360.0)) / c(2.0)
+    } else {
+        (h1 + h2 - c(360.0)) / c(2.0)
+    };
+
+    let t = C::Scalar::ONE
+        - c(0.17) * to_radians(h_bar - c(30.0)).cos()
+        + c(0.24) * to_radians(c(2.0) * h_bar).cos()
+        + c(0.32) * to_radians(c(3.0) * h_bar + c(6.0)).cos()
+        - c(0.20) * to_radians(c(4.0) * h_bar - c(63.0)).cos();
+    let delta_theta = c(30.0) * (-((h_bar - c(275.0)) / c(25.0)) * ((h_bar - c(275.0)) / c(25.0))).exp();
+    let r_c = c(2.0) * (pow7(c_bar) / (pow7(c_bar) + twenty_five_7)).sqrt();
+    let l_50 = (l_bar - c(50.0)) * (l_bar - c(50.0));
+    let s_l = C::Scalar::ONE + c(0.015) * l_50 / (c(20.0) + l_50).sqrt();
+    let s_c = C::Scalar::ONE + c(0.045) * c_bar;
+    let s_h = C::Scalar::ONE + c(0.015) * c_bar * t;
+    let r_t = -(to_radians(c(2.0) * delta_theta)).sin() * r_c;
+
+    let (l, c, h) = (delta_l / s_l, delta_c / s_c, delta_h / s_h);
+    (l * l + c * c + h * h + r_t * c * h).sqrt()
+}
+
+// Interpolates the colors in Oklab, and alpha linearly.
+#[inline]
+pub fn perceptual_mix<C: Color + Add<Output = C> + Mul<C::Scalar, Output = C>>(x: C, y: C, a: C::Scalar) -> C {
+    let mixed = x * (C::Scalar::ONE - a) + y * a;
+    let x = convert_linear_srgb_to_oklab(x).rgb();
+    let y = convert_linear_srgb_to_oklab(y).rgb();
+    convert_oklab_to_linear_srgb(mixed.with_rgb(x * (C::Scalar::ONE - a) + y * a))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        convert_d50_xyz_to_lab, convert_d65_xyz_to_linear_srgb, convert_lab_to_d50_xyz, convert_lab_to_lch, convert_lab_to_linear_srgb,
+        convert_lch_to_lab, convert_linear_srgb_to_lab, convert_linear_srgb_to_oklab, convert_linear_srgb_to_oklch,
+        convert_oklab_to_linear_srgb, convert_oklch_to_linear_srgb, delta_e_2000, delta_e_76, vec3,
+    };
+
+    fn close(a: vec3<f64>, b: vec3<f64>, tolerance: f64) -> bool {
+        (a - b).0.iter().all(|d| d.abs() < tolerance)
+    }
+
+    // Pairs from the test data of Sharma, Wu and Dalal, given to four
+    // decimals.
+    #[test]
+    fn ciede2000_reference_pairs() {
+        let pairs: [([f64; 3], [f64; 3], f64); 20] = [
+            ([50.0, 2.6772, -79.7751], [50.0, 0.0, -82.7485], 2.0425),
+            ([50.0, 3.1571, -77.2803], [50.0, 0.0, -82.7485], 2.8615),
+            ([50.0, 2.8361, -74.0200], [50.0, 0.0, -82.7485], 3.4412),
+            ([50.0, -1.3802, -84.2814], [50.0, 0.0, -82.7485], 1.0000),
+            ([50.0, 0.0, 0.0], [50.0, -1.0, 2.0], 2.3669),
+            ([50.0, -1.0, 2.0], [50.0, 0.0, 0.0], 2.3669),
+            ([50.0, 2.49, -0.001], [50.0, -2.49, 0.0009], 7.1792),
+            ([50.0, 2.49, -0.001], [50.0, -2.49, 0.0010], 7.1792),
+            ([50.0, 2.49, -0.001], [50.0, -2.49, 0.0011], 7.2195),
+            ([50.0, 2.49, -0.001], [50.0, -2.49, 0.0012], 7.2195),
+            ([50.0, 2.5, 0.0], [73.0, 25.0, -18.0], 27.1492),
+            ([50.0, 2.5, 0.0], [61.0, -5.0, 29.0], 22.8977),
+            ([50.0, 2.5, 0.0], [56.0, -27.0, -3.0], 31.9030),
+            ([50.0, 2.5, 0.0], [58.0, 24.0, 15.0], 19.4535),
+            ([50.0, 2.5, 0.0], [50.0, 3.1736, 0.5854], 1.0000),
+            ([60.2574, -34.0099, 36.2677], [60.4626, -34.1751, 39.4387], 1.2644),
+            ([63.0109, -31.0961, -5.8663], [62.8187, -29.7946, -4.0864], 1.2630),
+            ([61.2901, 3.7196, -5.3901], [61.4292, 2.2480, -4.9620], 1.8731),
+            ([35.0831, -44.1164, 3.7933], [35.0232, -40.0716, 1.5901], 1.8645),
+            ([90.8027, -2.0831, 1.4410], [91.1528, -1.6435, 0.0447], 1.4441),
+        ];
+        for (x, y, expected) in pairs {
+            let (x, y) = (vec3::from(x), vec3::from(y));
+            assert!((delta_e_2000(x, y) - expected).abs() < 5e-5, "{x:?} {y:?}");
+            assert!((delta_e_2000(y, x) - expected).abs() < 5e-5, "{y:?} {x:?}");
+        }
+        assert_eq!(delta_e_2000(vec3::new(50.0, 2.5, 0.0), vec3::new(50.0, 2.5, 0.0)), 0.0);
+        assert_eq!(delta_e_76(vec3::new(50.0, 0.0, 0.0), vec3::new(53.0, 4.0, 0.0)), 5.0);
+    }
+
+    // The XYZ table from Ottosson's post, given to three decimals, and the
+    // sRGB primaries as CSS Color 4 converts them.
+    #[test]
+    fn oklab_reference_values() {
+        let table = [
+            ([0.950, 1.000, 1.089], [1.000, 0.000, 0.000]),
+            ([1.000, 0.000, 0.000], [0.450, 1.236, -0.019]),
+            ([0.000, 1.000, 0.000], [0.922, -0.671, 0.263]),
+            ([0.000, 0.000, 1.000], [0.153, -1.415, -0.449]),
+        ];
+        for (xyz, oklab) in table {
+            let linear = convert_d65_xyz_to_linear_srgb(vec3::from(xyz));
+            assert!(close(convert_linear_srgb_to_oklab(linear), vec3::from(oklab), 1e-3), "{xyz:?}");
+        }
+        let red = convert_linear_srgb_to_oklab(vec3::new(1.0, 0.0, 0.0));
+        assert!(close(red, vec3::new(0.627_955_4, 0.224_863_1, 0.125_846_3), 1e-6), "{red:?}");
+        let white = convert_linear_srgb_to_oklab(vec3::splat(1.0));
+        assert!(close(white, vec3::new(1.0, 0.0, 0.0), 1e-6), "{white:?}");
+    }
+
+    #[test]
+    fn lab_reference_values() {
+        let white = convert_d50_xyz_to_lab(vec3::new(0.96422, 1.0, 0.82521));
+        assert!(close(white, vec3::new(100.0, 0.0, 0.0), 1e-9), "{white:?}");
+        // Both sides of the linear segment near black.
+        let dark = convert_d50_xyz_to_lab(vec3::new(0.96422, 1.0, 0.82521) * 0.001);
+        assert!(close(dark, vec3::new(24389.0 / 27.0 * 0.001, 0.0, 0.0), 1e-9), "{dark:?}");
+        let grey = convert_d50_xyz_to_lab(vec3::new(0.96422, 1.0, 0.82521) * 0.18);
+        assert!(close(grey, vec3::new(49.496_107, 0.0, 0.0), 1e-6), "{grey:?}");
+    }
+
+    #[test]
+    fn round_trips() {
+        for i in 0..6 * 6 * 6 {
+            let rgb = vec3::new((i % 6) as f64, (i / 6 % 6) as f64, (i / 36) as f64) / 5.0;
+            assert!(close(convert_oklab_to_linear_srgb(convert_linear_srgb_to_oklab(rgb)), rgb, 1e-6), "{rgb:?}");
+            assert!(close(convert_oklch_to_linear_srgb(convert_linear_srgb_to_oklch(rgb)), rgb, 1e-6), "{rgb:?}");
+            assert!(close(convert_lab_to_linear_srgb(convert_linear_srgb_to_lab(rgb)), rgb, 1e-6), "{rgb:?}");
+            let lab = convert_linear_srgb_to_lab(rgb);
+            assert!(close(convert_lch_to_lab(convert_lab_to_lch(lab)), lab, 1e-9), "{rgb:?}");
+            assert!(close(convert_d50_xyz_to_lab(convert_lab_to_d50_xyz(lab)), lab, 1e-9), "{rgb:?}");
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{gtc::color_space::map_rgb, mat4, vec3, vec4, Color, Float, Number};
+
+// Hue is in degrees, saturation, value and lightness in [0, 1].
+
+#[inline]
+fn max3<T: Float>(rgb: vec3<T>) -> T {
+    let m = if rgb.x > rgb.y { rgb.x } else { rgb.y };
+    if m > rgb.z { m } else { rgb.z }
+}
+
+#[inline]
+fn min3<T: Float>(rgb: vec3<T>) -> T {
+    let m = if rgb.x < rgb.y { rgb.x } else { rgb.y };
+    if m < rgb.z { m } else { rgb.z }
+}
+
+// Greys have no hue and get zero.
+#[inline]
+fn hue<T: Float>(rgb: vec3<T>, max: T, delta: T) -> T {
+    if delta == T::ZERO {
+        return T::ZERO;
+    }
+    let two = T::from_f64(2.0);
+    let h = if rgb.x == max {
+        (rgb.y - rgb.z) / delta
+    } else if rgb.y == max {
+        two + (rgb.z - rgb.x) / delta
+    } else {
+        two + two + (rgb.x - rgb.y) / delta
+    };
+    let h = h * T::from_f64(60.0);
+    if h < T::ZERO { h + T::from_f64(360.0) } else { h }
+}
+
+// Picks the rgb for a hue from its largest, middle and smallest component.
+#[inline]
+fn from_hue<T: Float>(hue: T, max: T, min: T) -> vec3<T> {
+    let six = T::from_f64(6.0);
+    let h = hue / T::from_f64(60.0);
+    let h = h - (h / six).floor() * six;
+    let sector = h.floor();
+    let f = h - sector;
+    let rising = min + (max - min) * f;
+    let falling = max - (max - min) * f;
+    match sector {
+        s if s < T::ONE => vec3::new(max, rising, min),
+        s if s < T::from_f64(2.0) => vec3::new(falling, max, min),
+        s if s < T::from_f64(3.0) => vec3::new(min, max, rising),
+        s if s < T::from_f64(4.0) => vec3::new(min, falling, max),
+        s if s < T::from_f64(5.0) => vec3::new(rising, min, max),
+        _ => vec3::new(max, min, falling),
+    }
+}
+
+#[inline]
+pub fn rgb_to_hsv<C: Color>(color: C) -> C {
+    map_rgb(color, |rgb| {
+        let max = max3(rgb);
+        let delta = max - min3(rgb);
+        let s = if max == C::Scalar::ZERO { C::Scalar::ZERO } else { delta / max };
+        vec3::new(hue(rgb, max, delta), s, max)
+    })
+}
+
+#[inline]
+pub fn hsv_to_rgb<C: Color>(color: C) -> C {
+    map_rgb(color, |hsv| from_hue(hsv.x, hsv.z, hsv.z * (C::Scalar::ONE - hsv.y)))
+}
+
+#[inline]
+pub fn rgb_to_hsl<C: Color>(color: C) -> C {
+    map_rgb(color, |rgb| {
+        let max = max3(rgb);
+        let min = min3(rgb);
+        let delta = max - min;
+        let l = (max + min) / C::Scalar::from_f64(2.0);
+        let s = if delta == C::Scalar::ZERO {
+            C::Scalar::ZERO
+        } else {
+            delta / (C::Scalar::ONE - (l + l - C::Scalar::ONE).abs())
+        };
+        vec3::new(hue(rgb, max, delta), s, l)
+    })
+}
+
+#[inline]
+pub fn hsl_to_rgb<C: Color>(color: C) -> C {
+    map_rgb(color, |hsl| {
+        let half_chroma = (C::Scalar::ONE - (hsl.z + hsl.z - C::Scalar::ONE).abs()) * hsl.y / C::Scalar::from_f64(2.0);
+        from_hue(hsl.x, hsl.z + half_chroma, hsl.z - half_chroma)
+    })
+}
+
+// GLM's luma weights.
+#[inline]
+pub fn luminosity<C: Color>(color: C) -> C::Scalar {
+    color.rgb().dot(vec3::new(C::Scalar::from_f64(0.33), C::Scalar::from_f64(0.59), C::Scalar::from_f64(0.11)))
+}
+
+impl<T: Float> mat4<T> {
+    // Scales the saturation of a linear rgb color around its Rec. 709
+    // luminance, keeping alpha.
+    #[inline]
+    pub fn saturation(s: T) -> Self {
+        let weights = vec3::new(T::from_f64(0.2126), T::from_f64(0.7152), T::from_f64(0.0722));
+        let col = weights * (T::ONE - s);
+        let mut result = Self::identity();
+        result.value[0] = vec4::new(col.x + s, col.x, col.x, T::ZERO);
+        result.value[1] = vec4::new(col.y, col.y + s, col.y, T::ZERO);
+        result.value[2] = vec4::new(col.z, col.z, col.z + s, T::ZERO);
+        result
+    }
+}
+
+#[inline]
+pub fn saturation<C: Color>(s: C::Scalar, color: C) -> C {
+    map_rgb(color, |rgb| {
+        let v = mat4::saturation(s) * vec4::new(rgb.x, rgb.y, rgb.z, C::Scalar::ZERO);
+        vec3::new(v.x, v.y, v.z)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{hsl_to_rgb, hsv_to_rgb, rgb_to_hsl, rgb_to_hsv, vec3, vec4};
+
+    fn close(a: vec3<f64>, b: vec3<f64>) -> bool {
+        (a - b).0.iter().all(|d| d.abs() < 1e-12)
+    }
+
+    fn colors() -> impl Iterator<Item = vec3<f64>> {
+        (0..6 * 6 * 6).map(|i| vec3::new((i % 6) as f64, (i / 6 % 6) as f64, (i / 36) as f64) / 5.0)
+    }
+
+    #[test]
+    fn reference_values() {
+        let cases = [
+            (vec3::new(1.0, 0.0, 0.0), vec3::new(0.0, 1.0, 1.0), vec3::new(0.0, 1.0, 0.5)),
+            (vec3::new(0.0, 0.5, 1.0), vec3::new(210.0, 1.0, 1.0), vec3::new(210.0, 1.0, 0.5)),
+            (vec3::new(0.75, 0.25, 0.75), vec3::new(300.0, 2.0 / 3.0, 0.75), vec3::new(300.0, 0.5, 0.5)),
+            (vec3::new(0.5, 1.0, 0.5), vec3::new(120.0, 0.5, 1.0), vec3::new(120.0, 1.0, 0.75)),
+            (vec3::new(0.2, 0.2, 0.2), vec3::new(0.0, 0.0, 0.2), vec3::new(0.0, 0.0, 0.2)),
+        ];
+        for (rgb, hsv, hsl) in cases {
+            assert!(close(rgb_to_hsv(rgb), hsv), "{rgb:?}");
+            assert!(close(rgb_to_hsl(rgb), hsl), "{rgb:?}");
+            assert!(close(hsv_to_rgb(hsv), rgb), "{rgb:?}");
+            assert!(close(hsl_to_rgb(hsl), rgb), "{rgb:?}");
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        for rgb in colors() {
+            assert!(close(hsv_to_rgb(rgb_to_hsv(rgb)), rgb), "{rgb:?}");
+            assert!(close(hsl_to_rgb(rgb_to_hsl(rgb)), rgb), "{rgb:?}");
+        }
+        let color = vec4::new(0.3f64, 0.6, 0.9, 0.5);
+        assert_eq!(rgb_to_hsv(color).w, 0.5);
+        assert!((hsl_to_rgb(rgb_to_hsl(color)) - color).0.iter().all(|d| d.abs() < 1e-12));
+    }
+}
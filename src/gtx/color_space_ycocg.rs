@@ -0,0 +1,77 @@
+use crate::{gtc::color_space::map_rgb, vec3, Color, Float};
+
+#[inline]
+pub fn rgb_to_ycocg<C: Color>(color: C) -> C {
+    map_rgb(color, |rgb| {
+        let half = C::Scalar::from_f64(0.5);
+        let quarter = C::Scalar::from_f64(0.25);
+        vec3::new(
+            rgb.x * quarter + rgb.y * half + rgb.z * quarter,
+            rgb.x * half - rgb.z * half,
+            -rgb.x * quarter + rgb.y * half - rgb.z * quarter)
+    })
+}
+
+#[inline]
+pub fn ycocg_to_rgb<C: Color>(color: C) -> C {
+    map_rgb(color, |ycocg| {
+        vec3::new(
+            ycocg.x + ycocg.y - ycocg.z,
+            ycocg.x + ycocg.z,
+            ycocg.x - ycocg.y - ycocg.z)
+    })
+}
+
+// The reversible variant, with Co and Cg at twice the scale.
+#[inline]
+pub fn rgb_to_ycocg_r<C: Color>(color: C) -> C {
+    map_rgb(color, |rgb| {
+        let half = C::Scalar::from_f64(0.5);
+        let co = rgb.x - rgb.z;
+        let t = rgb.z + co * half;
+        let cg = rgb.y - t;
+        vec3::new(t + cg * half, co, cg)
+    })
+}
+
+#[inline]
+pub fn ycocg_r_to_rgb<C: Color>(color: C) -> C {
+    map_rgb(color, |ycocg| {
+        let half = C::Scalar::from_f64(0.5);
+        let t = ycocg.x - ycocg.z * half;
+        let g = ycocg.z + t;
+        let b = t - ycocg.y * half;
+        vec3::new(b + ycocg.y, g, b)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{rgb_to_ycocg, rgb_to_ycocg_r, vec3, ycocg_r_to_rgb, ycocg_to_rgb};
+
+    #[test]
+    fn reference_values() {
+        assert_eq!(rgb_to_ycocg(vec3::new(1.0f32, 1.0, 1.0)), vec3::new(1.0, 0.0, 0.0));
+        assert_eq!(rgb_to_ycocg(vec3::new(1.0f32, 0.0, 0.0)), vec3::new(0.25, 0.5, -0.25));
+        assert_eq!(rgb_to_ycocg(vec3::new(0.0f32, 1.0, 0.0)), vec3::new(0.5, 0.0, 0.5));
+        assert_eq!(rgb_to_ycocg(vec3::new(0.0f32, 0.0, 1.0)), vec3::new(0.25, -0.5, -0.25));
+        assert_eq!(rgb_to_ycocg_r(vec3::new(1.0f32, 0.0, 0.0)), vec3::new(0.25, 1.0, -0.5));
+    }
+
+    #[test]
+    fn round_trips() {
+        for i in 0..16 * 16 * 16 {
+            let rgb = vec3::new((i % 16) as f32, (i / 16 % 16) as f32, (i / 256) as f32) / 15.0;
+            assert!((ycocg_to_rgb(rgb_to_ycocg(rgb)) - rgb).0.iter().all(|d| d.abs() < 1e-6));
+        }
+    }
+
+    // The lifting steps only halve, so integer channels come back exactly.
+    #[test]
+    fn reversible_round_trips_exactly() {
+        for i in 0..32 * 32 * 32 {
+            let rgb = vec3::new((i % 32) as f32, (i / 32 % 32) as f32, (i / 1024) as f32) * 8.0;
+            assert_eq!(ycocg_r_to_rgb(rgb_to_ycocg_r(rgb)), rgb);
+        }
+    }
+}
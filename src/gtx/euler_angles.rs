@@ -0,0 +1,232 @@
+use crate::{mat4, vec3, Float, Matrix, Vector};
+
+// Rotations act on column vectors, so euler_angle_xyz(t1, t2, t3) is
+// euler_angle_x(t1) * euler_angle_y(t2) * euler_angle_z(t3), as in GLM.
+
+#[inline]
+fn rotation<T: Float>(axis: usize, angle: T) -> mat4<T> {
+    let (j, k) = ((axis + 1) % 3, (axis + 2) % 3);
+    let (s, c) = (angle.sin(), angle.cos());
+    let mut result = mat4::identity();
+    result.value[j].0[j] = c;
+    result.value[j].0[k] = s;
+    result.value[k].0[j] = -s;
+    result.value[k].0[k] = c;
+    result
+}
+
+#[inline]
+fn derived_rotation<T: Float>(axis: usize, angle: T, angular_velocity: T) -> mat4<T> {
+    let (j, k) = ((axis + 1) % 3, (axis + 2) % 3);
+    let (s, c) = (angle.sin() * angular_velocity, angle.cos() * angular_velocity);
+    let mut result = Matrix::from_cols([Vector::ZERO; 4]);
+    result.value[j].0[j] = -s;
+    result.value[j].0[k] = c;
+    result.value[k].0[j] = -c;
+    result.value[k].0[k] = -s;
+    result
+}
+
+// Inverts rotation(i, t1) * rotation(j, t2) * rotation(k, t3). Tait-Bryan
+// orders give t2 in [-pi/2, pi/2] and proper Euler orders (i == k) give t2
+// in [0, pi], with t1 and t3 in [-pi, pi]. The third angle is solved after
+// undoing the first so that it absorbs the whole rotation at gimbal lock.
+#[inline]
+fn extract<T: Float>(m: &mat4<T>, i: usize, j: usize, k: usize) -> vec3<T> {
+    let at = |row: usize, column: usize| m.value[column].0[row];
+    let sign = if j == (i + 1) % 3 { T::ONE } else { -T::ONE };
+    // The axis that follows j, or that is missing for proper Euler orders.
+    let l = if i == k { 3 - i - j } else { k };
+
+    let (t1, t2) = if i == k {
+        (
+            at(j, i).atan2(-sign * at(l, i)),
+            (at(i, j) * at(i, j) + at(i, l) * at(i, l)).sqrt().atan2(at(i, i)),
+        )
+    } else {
+        (
+            (-sign * at(j, k)).atan2(at(k, k)),
+            (sign * at(i, k)).atan2((at(j, k) * at(j, k) + at(k, k) * at(k, k)).sqrt()),
+        )
+    };
+
+    let (s1, c1) = (t1.sin(), t1.cos());
+    let t3 = if i == k {
+        (-sign * (c1 * at(j, l) + sign * s1 * at(l, l))).atan2(c1 * at(j, j) + sign * s1 * at(l, j))
+    } else {
+        (sign * (c1 * at(j, i) + sign * s1 * at(l, i))).atan2(c1 * at(j, j) + sign * s1 * at(l, j))
+    };
+    vec3::new(t1, t2, t3)
+}
+
+macro_rules! euler_angles_2 {
+    ($($name:ident = $i:literal $j:literal;)*) => {
+        impl<T: Float> mat4<T> {
+            $(
+                #[inline]
+                pub fn $name(t1: T, t2: T) -> Self {
+                    rotation($i, t1) * rotation($j, t2)
+                }
+            )*
+        }
+    }
+}
+
+macro_rules! euler_angles_3 {
+    ($($name:ident, $extract:ident = $i:literal $j:literal $k:literal;)*) => {
+        impl<T: Float> mat4<T> {
+            $(
+                #[inline]
+                pub fn $name(t1: T, t2: T, t3: T) -> Self {
+                    rotation($i, t1) * rotation($j, t2) * rotation($k, t3)
+                }
+
+                #[inline]
+                pub fn $extract(&self) -> vec3<T> {
+                    extract(self, $i, $j, $k)
+                }
+            )*
+        }
+    }
+}
+
+impl<T: Float> mat4<T> {
+    #[inline]
+    pub fn euler_angle_x(angle: T) -> Self {
+        rotation(0, angle)
+    }
+
+    #[inline]
+    pub fn euler_angle_y(angle: T) -> Self {
+        rotation(1, angle)
+    }
+
+    #[inline]
+    pub fn euler_angle_z(angle: T) -> Self {
+        rotation(2, angle)
+    }
+
+    // The time derivatives of the single axis rotations, for an angle that
+    // changes at the given angular velocity.
+    #[inline]
+    pub fn derived_euler_angle_x(angle: T, angular_velocity: T) -> Self {
+        derived_rotation(0, angle, angular_velocity)
+    }
+
+    #[inline]
+    pub fn derived_euler_angle_y(angle: T, angular_velocity: T) -> Self {
+        derived_rotation(1, angle, angular_velocity)
+    }
+
+    #[inline]
+    pub fn derived_euler_angle_z(angle: T, angular_velocity: T) -> Self {
+        derived_rotation(2, angle, angular_velocity)
+    }
+
+    #[inline]
+    pub fn yaw_pitch_roll(yaw: T, pitch: T, roll: T) -> Self {
+        Self::euler_angle_yxz(yaw, pitch, roll)
+    }
+}
+
+euler_angles_2! {
+    euler_angle_xy = 0 1;
+    euler_angle_yx = 1 0;
+    euler_angle_xz = 0 2;
+    euler_angle_zx = 2 0;
+    euler_angle_yz = 1 2;
+    euler_angle_zy = 2 1;
+}
+
+euler_angles_3! {
+    euler_angle_xyz, extract_euler_angle_xyz = 0 1 2;
+    euler_angle_yzx, extract_euler_angle_yzx = 1 2 0;
+    euler_angle_zxy, extract_euler_angle_zxy = 2 0 1;
+    euler_angle_xzy, extract_euler_angle_xzy = 0 2 1;
+    euler_angle_zyx, extract_euler_angle_zyx = 2 1 0;
+    euler_angle_yxz, extract_euler_angle_yxz = 1 0 2;
+    euler_angle_xyx, extract_euler_angle_xyx = 0 1 0;
+    euler_angle_xzx, extract_euler_angle_xzx = 0 2 0;
+    euler_angle_yxy, extract_euler_angle_yxy = 1 0 1;
+    euler_angle_yzy, extract_euler_angle_yzy = 1 2 1;
+    euler_angle_zxz, extract_euler_angle_zxz = 2 0 2;
+    euler_angle_zyz, extract_euler_angle_zyz = 2 1 2;
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    use crate::{mat4, vec3};
+
+    type Build = fn(f64, f64, f64) -> mat4<f64>;
+    type Extract = fn(&mat4<f64>) -> vec3<f64>;
+    type Rotation = fn(f64) -> mat4<f64>;
+    type Derived = fn(f64, f64) -> mat4<f64>;
+
+    // Each order, and whether it is a proper Euler order.
+    const ORDERS: [(Build, Extract, bool); 12] = [
+        (mat4::euler_angle_xyz, mat4::extract_euler_angle_xyz, false),
+        (mat4::euler_angle_yzx, mat4::extract_euler_angle_yzx, false),
+        (mat4::euler_angle_zxy, mat4::extract_euler_angle_zxy, false),
+        (mat4::euler_angle_xzy, mat4::extract_euler_angle_xzy, false),
+        (mat4::euler_angle_zyx, mat4::extract_euler_angle_zyx, false),
+        (mat4::euler_angle_yxz, mat4::extract_euler_angle_yxz, false),
+        (mat4::euler_angle_xyx, mat4::extract_euler_angle_xyx, true),
+        (mat4::euler_angle_xzx, mat4::extract_euler_angle_xzx, true),
+        (mat4::euler_angle_yxy, mat4::extract_euler_angle_yxy, true),
+        (mat4::euler_angle_yzy, mat4::extract_euler_angle_yzy, true),
+        (mat4::euler_angle_zxz, mat4::extract_euler_angle_zxz, true),
+        (mat4::euler_angle_zyz, mat4::extract_euler_angle_zyz, true),
+    ];
+
+    fn close(a: mat4<f64>, b: mat4<f64>, tolerance: f64) -> bool {
+        a.value.iter().zip(b.value.iter()).all(|(a, b)| (*a - *b).0.iter().all(|x| x.abs() <= tolerance))
+    }
+
+    #[test]
+    fn extract_inverts_build() {
+        for (build, extract, proper) in ORDERS {
+            // The middle angle stays within the range extract returns, up to
+            // a thousandth of a degree from gimbal lock.
+            let middles = if proper { [0.3, 1.2, 2.9, 1e-5, PI - 1e-5] } else { [0.3, -1.2, 1.5, FRAC_PI_2 - 1e-5, 1e-5 - FRAC_PI_2] };
+            for t2 in middles {
+                for (t1, t3) in [(0.4, -0.9), (-2.8, 3.0), (1.7, 0.1), (-0.2, -2.2)] {
+                    let angles = extract(&build(t1, t2, t3));
+                    let expected = vec3::new(t1, t2, t3);
+                    assert!((angles - expected).0.iter().all(|d| d.abs() < 1e-9), "{angles:?} != {expected:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn extract_rebuilds_at_gimbal_lock() {
+        for (build, extract, proper) in ORDERS {
+            let middles = if proper { [0.0, PI] } else { [FRAC_PI_2, -FRAC_PI_2] };
+            for t2 in middles {
+                for (t1, t3) in [(0.4, -0.9), (-2.8, 3.0), (1.7, 0.1)] {
+                    let m = build(t1, t2, t3);
+                    let angles = extract(&m);
+                    assert!(close(build(angles.x, angles.y, angles.z), m, 1e-12));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn derivatives_match_finite_differences() {
+        let h = 1e-6;
+        let derivatives: [(Rotation, Derived); 3] = [
+            (mat4::euler_angle_x, mat4::derived_euler_angle_x),
+            (mat4::euler_angle_y, mat4::derived_euler_angle_y),
+            (mat4::euler_angle_z, mat4::derived_euler_angle_z),
+        ];
+        for (rotation, derived) in derivatives {
+            for (angle, angular_velocity) in [(0.0, 1.0), (0.7, 2.5), (-2.1, -0.3), (3.0, 0.0)] {
+                let difference = (rotation(angle + h) - rotation(angle - h)) * (angular_velocity / (2.0 * h));
+                assert!(close(derived(angle, angular_velocity), difference, 1e-8));
+            }
+        }
+    }
+}
@@ -0,0 +1,294 @@
+use crate::{vec2, vec3, Float};
+
+// Rays start at their origin and only hit at distances t >= 0, lines extend
+// both ways and segments go from t = 0 at a to t = 1 at b. Directions need
+// not be normalized, distances are in units of the direction. Parallel and
+// degenerate configurations are detected relative to the size of the input,
+// so the tests behave the same at any scale.
+
+#[inline]
+pub fn intersect_ray_plane<T: Float>(orig: vec3<T>, dir: vec3<T>, plane_orig: vec3<T>, plane_normal: vec3<T>) -> Option<T> {
+    let d = dir.dot(plane_normal);
+    if d.abs() <= T::EPSILON * dir.length() * plane_normal.length() {
+        return None;
+    }
+    let distance = (plane_orig - orig).dot(plane_normal) / d;
+    if distance >= T::ZERO { Some(distance) } else { None }
+}
+
+// Möller-Trumbore for both windings. Gives the barycentric coordinates of
+// the hit relative to v1 and v2, so that the position is
+// v0 * (1 - u - v) + v1 * u + v2 * v, and the distance along the line.
+#[inline]
+fn line_triangle<T: Float>(orig: vec3<T>, dir: vec3<T>, v0: vec3<T>, v1: vec3<T>, v2: vec3<T>) -> Option<(vec2<T>, T)> {
+    let e1 = v1 - v0;
+    let e2 = v2 - v0;
+    let p = dir.cross(e2);
+    let det = e1.dot(p);
+    if det.abs() <= T::EPSILON * e1.length() * e2.length() * dir.length() {
+        return None;
+    }
+    let inv_det = T::ONE / det;
+
+    let s = orig - v0;
+    let u = s.dot(p) * inv_det;
+    if u < T::ZERO || u > T::ONE {
+        return None;
+    }
+    let q = s.cross(e1);
+    let v = dir.dot(q) * inv_det;
+    if v < T::ZERO || u + v > T::ONE {
+        return None;
+    }
+    Some((vec2::new(u, v), e2.dot(q) * inv_det))
+}
+
+#[inline]
+pub fn intersect_ray_triangle<T: Float>(orig: vec3<T>, dir: vec3<T>, v0: vec3<T>, v1: vec3<T>, v2: vec3<T>) -> Option<(vec2<T>, T)> {
+    line_triangle(orig, dir, v0, v1, v2).filter(|&(_, distance)| distance >= T::ZERO)
+}
+
+#[inline]
+pub fn intersect_line_triangle<T: Float>(orig: vec3<T>, dir: vec3<T>, v0: vec3<T>, v1: vec3<T>, v2: vec3<T>) -> Option<(vec2<T>, T)> {
+    line_triangle(orig, dir, v0, v1, v2)
+}
+
+#[inline]
+pub fn intersect_segment_triangle<T: Float>(a: vec3<T>, b: vec3<T>, v0: vec3<T>, v1: vec3<T>, v2: vec3<T>) -> Option<(vec2<T>, T)> {
+    line_triangle(a, b - a, v0, v1, v2).filter(|&(_, t)| t >= T::ZERO && t <= T::ONE)
+}
+
+// Both distances along the line, nearest first. The discriminant is taken
+// from the distance between the center and the line, which keeps it
+// accurate for small spheres far from the origin, and the roots avoid
+// cancellation.
+#[inline]
+fn line_sphere<T: Float>(orig: vec3<T>, dir: vec3<T>, center: vec3<T>, radius: T) -> Option<(T, T)> {
+    let a = dir.dot(dir);
+    if a == T::ZERO {
+        return None;
+    }
+    let oc = orig - center;
+    let b = oc.dot(dir);
+    let closest = oc - dir * (b / a);
+    let discriminant = radius * radius - closest.dot(closest);
+    if discriminant < T::ZERO {
+        return None;
+    }
+    if discriminant == T::ZERO {
+        let t = -b / a;
+        return Some((t, t));
+    }
+    let h = (a * discriminant).sqrt();
+    let q = if b < T::ZERO { h - b } else { -b - h };
+    let (t0, t1) = ((oc.dot(oc) - radius * radius) / q, q / a);
+    if t0 <= t1 { Some((t0, t1)) } else { Some((t1, t0)) }
+}
+
+// The nearest hit in front of the origin, which is the exit point when the
+// origin is inside the sphere.
+#[inline]
+pub fn intersect_ray_sphere<T: Float>(orig: vec3<T>, dir: vec3<T>, center: vec3<T>, radius: T) -> Option<T> {
+    let (t0, t1) = line_sphere(orig, dir, center, radius)?;
+    if t0 >= T::ZERO {
+        Some(t0)
+    } else if t1 >= T::ZERO {
+        Some(t1)
+    } else {
+        None
+    }
+}
+
+// The position and outward unit normal of the nearest hit.
+#[inline]
+pub fn intersect_ray_sphere_surface<T: Float>(orig: vec3<T>, dir: vec3<T>, center: vec3<T>, radius: T) -> Option<(vec3<T>, vec3<T>)> {
+    let distance = intersect_ray_sphere(orig, dir, center, radius)?;
+    let position = orig + dir * distance;
+    Some((position, (position - center) / radius))
+}
+
+// The line through point0 and point1, with the position and outward unit
+// normal of both hits, the one nearest to point0 in the direction of point1
+// first. A tangent line gives the same point twice.
+#[inline]
+pub fn intersect_line_sphere<T: Float>(point0: vec3<T>, point1: vec3<T>, center: vec3<T>, radius: T) -> Option<[(vec3<T>, vec3<T>); 2]> {
+    let dir = point1 - point0;
+    let (t0, t1) = line_sphere(point0, dir, center, radius)?;
+    Some([t0, t1].map(|t| {
+        let position = point0 + dir * t;
+        (position, (position - center) / radius)
+    }))
+}
+
+// The slab test, giving the distances where the ray enters and leaves the
+// box. The entry is zero when the origin is inside, and a ray parallel to a
+// slab hits only if its origin lies within it, boundaries included.
+#[inline]
+pub fn intersect_ray_aabb<T: Float>(orig: vec3<T>, dir: vec3<T>, min: vec3<T>, max: vec3<T>) -> Option<(T, T)> {
+    let mut near = T::ZERO;
+    let mut far = T::INFINITY;
+    for i in 0..3 {
+        let (o, d) = (orig.0[i], dir.0[i]);
+        if d == T::ZERO {
+            if o < min.0[i] || o > max.0[i] {
+                return None;
+            }
+            continue;
+        }
+        let t0 = (min.0[i] - o) / d;
+        let t1 = (max.0[i] - o) / d;
+        let (t0, t1) = if t0 <= t1 { (t0, t1) } else { (t1, t0) };
+        near = if t0 > near { t0 } else { near };
+        far = if t1 < far { t1 } else { far };
+        if near > far {
+            return None;
+        }
+    }
+    Some((near, far))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        intersect_line_sphere, intersect_line_triangle, intersect_ray_aabb, intersect_ray_plane, intersect_ray_sphere,
+        intersect_ray_sphere_surface, intersect_ray_triangle, intersect_segment_triangle, vec3,
+    };
+
+    // Every case runs at these scales, with positions and directions scaled
+    // alike so that the distances do not change.
+    const SCALES: [f64; 3] = [1e-3, 1.0, 1e3];
+
+    fn near(a: f64, b: f64) -> bool {
+        (a - b).abs() <= 1e-12 * b.abs().max(1.0)
+    }
+
+    fn close(a: vec3<f64>, b: vec3<f64>, s: f64) -> bool {
+        (a - b).0.iter().all(|d| d.abs() <= 1e-12 * s)
+    }
+
+    #[test]
+    fn ray_plane() {
+        for s in SCALES {
+            let (orig, normal) = (vec3::new(0.0, 0.0, 5.0) * s, vec3::new(0.0, 0.0, 1.0));
+            let down = vec3::new(0.0, 0.0, -1.0) * s;
+            assert!(near(intersect_ray_plane(orig, down, vec3::splat(0.0), normal).unwrap(), 5.0));
+            assert!(near(intersect_ray_plane(orig, down, vec3::new(3.0, -2.0, 0.0) * s, -normal).unwrap(), 5.0));
+            assert!(near(intersect_ray_plane(orig, vec3::new(1.0, 0.0, -1.0) * s, vec3::splat(0.0), normal).unwrap(), 5.0));
+            // Behind the origin and parallel.
+            assert_eq!(intersect_ray_plane(orig, -down, vec3::splat(0.0), normal), None);
+            assert_eq!(intersect_ray_plane(orig, vec3::new(1.0, 2.0, 0.0) * s, vec3::splat(0.0), normal), None);
+            // An origin on the plane hits at zero.
+            assert_eq!(intersect_ray_plane(vec3::splat(0.0), down, vec3::splat(0.0), normal), Some(0.0));
+        }
+    }
+
+    #[test]
+    fn triangles() {
+        for s in SCALES {
+            let [v0, v1, v2] = [vec3::new(-1.0, -1.0, 0.0), vec3::new(1.0, -1.0, 0.0), vec3::new(0.0, 1.0, 0.0)].map(|v| v * s);
+            let (orig, down) = (vec3::new(0.25, 0.0, 5.0) * s, vec3::new(0.0, 0.0, -1.0) * s);
+            for (a, b, c) in [(v0, v1, v2), (v0, v2, v1)] {
+                let (uv, t) = intersect_ray_triangle(orig, down, a, b, c).unwrap();
+                assert!(near(t, 5.0));
+                let position = a * (1.0 - uv.x - uv.y) + b * uv.x + c * uv.y;
+                assert!(close(position, vec3::new(0.25, 0.0, 0.0) * s, s), "{position:?}");
+                assert_eq!(intersect_line_triangle(orig, down, a, b, c), Some((uv, t)));
+
+                // Outside the edges, behind the origin and parallel.
+                assert_eq!(intersect_ray_triangle(vec3::new(1.0, 1.0, 5.0) * s, down, a, b, c), None);
+                assert_eq!(intersect_ray_triangle(vec3::new(0.0, -1.5, 5.0) * s, down, a, b, c), None);
+                assert_eq!(intersect_ray_triangle(orig, -down, a, b, c), None);
+                assert!(near(intersect_line_triangle(orig, -down, a, b, c).unwrap().1, -5.0));
+                assert_eq!(intersect_ray_triangle(orig, vec3::new(1.0, 0.0, 0.0) * s, a, b, c), None);
+                assert_eq!(intersect_line_triangle(vec3::new(0.0, 0.0, 0.0), vec3::new(1.0, 1.0, 0.0) * s, a, b, c), None);
+            }
+        }
+    }
+
+    #[test]
+    fn segments() {
+        for s in SCALES {
+            let [v0, v1, v2] = [vec3::new(-1.0, -1.0, 0.0), vec3::new(1.0, -1.0, 0.0), vec3::new(0.0, 1.0, 0.0)].map(|v| v * s);
+            let (top, bottom) = (vec3::new(0.0, 0.0, 5.0) * s, vec3::new(0.0, 0.0, -5.0) * s);
+            assert!(near(intersect_segment_triangle(top, bottom, v0, v1, v2).unwrap().1, 0.5));
+            assert!(near(intersect_segment_triangle(bottom, top, v0, v2, v1).unwrap().1, 0.5));
+            // Endpoints on the triangle hit at either end.
+            let on = vec3::new(0.0, 0.0, 0.0);
+            assert_eq!(intersect_segment_triangle(top, on, v0, v1, v2).unwrap().1, 1.0);
+            assert_eq!(intersect_segment_triangle(on, bottom, v0, v1, v2).unwrap().1, 0.0);
+            // Stopping short of the triangle or starting past it.
+            assert_eq!(intersect_segment_triangle(top, vec3::new(0.0, 0.0, 0.1) * s, v0, v1, v2), None);
+            assert_eq!(intersect_segment_triangle(vec3::new(0.0, 0.0, -0.1) * s, bottom, v0, v1, v2), None);
+        }
+    }
+
+    #[test]
+    fn spheres() {
+        for s in SCALES {
+            let (center, radius) = (vec3::new(0.0, 0.0, -10.0) * s, 2.0 * s);
+            let forward = vec3::new(0.0, 0.0, -1.0) * s;
+            assert!(near(intersect_ray_sphere(vec3::splat(0.0), forward, center, radius).unwrap(), 8.0));
+            let (position, normal) = intersect_ray_sphere_surface(vec3::splat(0.0), forward, center, radius).unwrap();
+            assert!(close(position, vec3::new(0.0, 0.0, -8.0) * s, s));
+            assert!(close(normal, vec3::new(0.0, 0.0, 1.0), 1.0));
+
+            // From the center the ray hits on the way out, and the normal
+            // still points outward.
+            assert!(near(intersect_ray_sphere(center, forward, center, radius).unwrap(), 2.0));
+            let (_, normal) = intersect_ray_sphere_surface(center, forward, center, radius).unwrap();
+            assert!(close(normal, vec3::new(0.0, 0.0, -1.0), 1.0));
+
+            // Behind the origin, off to the side and tangent.
+            assert_eq!(intersect_ray_sphere(vec3::splat(0.0), -forward, center, radius), None);
+            assert_eq!(intersect_ray_sphere(vec3::new(2.1, 0.0, 0.0) * s, forward, center, radius), None);
+            assert!(near(intersect_ray_sphere(vec3::new(2.0, 0.0, 0.0) * s, forward, center, radius).unwrap(), 10.0));
+            assert_eq!(intersect_ray_sphere(vec3::splat(0.0), vec3::splat(0.0), center, radius), None);
+        }
+    }
+
+    #[test]
+    fn line_spheres() {
+        for s in SCALES {
+            let (center, radius) = (vec3::new(0.0, 0.0, -10.0) * s, 2.0 * s);
+            let [(p0, n0), (p1, n1)] = intersect_line_sphere(vec3::splat(0.0), vec3::new(0.0, 0.0, -1.0) * s, center, radius).unwrap();
+            assert!(close(p0, vec3::new(0.0, 0.0, -8.0) * s, s) && close(p1, vec3::new(0.0, 0.0, -12.0) * s, s));
+            assert!(close(n0, vec3::new(0.0, 0.0, 1.0), 1.0) && close(n1, vec3::new(0.0, 0.0, -1.0), 1.0));
+
+            // Lines extend behind their first point, and the order follows
+            // the direction from point0 to point1.
+            let [(p0, _), (p1, _)] = intersect_line_sphere(vec3::new(0.0, 0.0, -20.0) * s, vec3::new(0.0, 0.0, -21.0) * s, center, radius).unwrap();
+            assert!(close(p0, vec3::new(0.0, 0.0, -8.0) * s, s) && close(p1, vec3::new(0.0, 0.0, -12.0) * s, s));
+
+            let tangent = intersect_line_sphere(vec3::new(0.0, 2.0, 0.0) * s, vec3::new(0.0, 2.0, -1.0) * s, center, radius).unwrap();
+            assert_eq!(tangent[0], tangent[1]);
+            assert!(close(tangent[0].0, vec3::new(0.0, 2.0, -10.0) * s, s));
+            assert_eq!(intersect_line_sphere(vec3::new(0.0, 2.5, 0.0) * s, vec3::new(0.0, 2.5, -1.0) * s, center, radius), None);
+        }
+    }
+
+    #[test]
+    fn boxes() {
+        for s in SCALES {
+            let (min, max) = (vec3::splat(-1.0) * s, vec3::splat(1.0) * s);
+            let right = vec3::new(1.0, 0.0, 0.0) * s;
+            let (near_t, far_t) = intersect_ray_aabb(vec3::new(-5.0, 0.0, 0.0) * s, right, min, max).unwrap();
+            assert!(near(near_t, 4.0) && near(far_t, 6.0));
+            let (near_t, far_t) = intersect_ray_aabb(vec3::new(-5.0, -4.0, 0.5) * s, vec3::new(1.0, 1.0, 0.0) * s, min, max).unwrap();
+            assert!(near(near_t, 4.0) && near(far_t, 5.0));
+
+            // Inside, the entry is the origin.
+            let (near_t, far_t) = intersect_ray_aabb(vec3::new(0.5, 0.0, 0.0) * s, right, min, max).unwrap();
+            assert!(near_t == 0.0 && near(far_t, 0.5));
+
+            // Parallel to a slab: on its boundary hits, just outside misses.
+            let (near_t, far_t) = intersect_ray_aabb(vec3::new(-5.0, 1.0, -1.0) * s, right, min, max).unwrap();
+            assert!(near(near_t, 4.0) && near(far_t, 6.0));
+            assert_eq!(intersect_ray_aabb(vec3::new(-5.0, 1.0 + 1e-9, 0.0) * s, right, min, max), None);
+
+            // Past the box, behind the origin and beside it.
+            assert_eq!(intersect_ray_aabb(vec3::new(5.0, 0.0, 0.0) * s, right, min, max), None);
+            assert_eq!(intersect_ray_aabb(vec3::new(-5.0, 2.0, 0.0) * s, right, min, max), None);
+            assert_eq!(intersect_ray_aabb(vec3::new(-5.0, -5.0, 0.0) * s, vec3::new(1.0, 2.0, 0.0) * s, min, max), None);
+        }
+    }
+}
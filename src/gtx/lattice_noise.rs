@@ -0,0 +1,294 @@
+use core::{array, ops::Sub};
+
+use crate::{vec2, vec3, Cast, Float, Vector};
+
+// Value noise and Worley (cellular) noise. Both hash the integer coordinates
+// of lattice cells: value noise interpolates a value per lattice point with
+// a quintic fade, and Worley noise places one feature point per cell.
+
+pub trait LatticeNoise: Copy + Sub<Output = Self> {
+    type Scalar: Float;
+
+    // Value noise in [-1, 1] and its gradient.
+    fn value_noise_gradient(self) -> (Self::Scalar, Self);
+
+    // The distances to the nearest and second nearest feature points, each
+    // with its gradient.
+    fn worley_gradient(self) -> [(Self::Scalar, Self); 2];
+}
+
+// lowbias32, after Chris Wellons.
+#[inline]
+fn hash(mut x: u32) -> u32 {
+    x ^= x >> 16;
+    x = x.wrapping_mul(0x7feb352d);
+    x ^= x >> 15;
+    x = x.wrapping_mul(0x846ca68b);
+    x ^= x >> 16;
+    x
+}
+
+#[inline]
+fn hash_cell<const N: usize>(cell: [i32; N]) -> u32 {
+    cell.iter().fold(0x9e3779b9, |h, &c| hash(h ^ c as u32))
+}
+
+// Uniform in [0, 1).
+#[inline]
+fn unit<T: Float>(h: u32) -> T {
+    T::from_f64((h >> 8) as f64 * (1.0 / (1u32 << 24) as f64))
+}
+
+#[inline]
+fn split<T: Float + Cast<i32>, const N: usize>(p: Vector<T, N>) -> ([i32; N], Vector<T, N>) {
+    let floor = p.map(Float::floor);
+    (floor.0.map(Cast::cast), p - floor)
+}
+
+#[inline]
+fn value_noise_impl<T: Float + Cast<i32>, const N: usize>(p: Vector<T, N>) -> (T, Vector<T, N>) {
+    let (cell, f) = split(p);
+    let (six, fifteen, ten, thirty) = (T::from_f64(6.0), T::from_f64(15.0), T::from_f64(10.0), T::from_f64(30.0));
+    let u = f.map(|t| t * t * t * (t * (t * six - fifteen) + ten));
+    let du = f.map(|t| thirty * t * t * (t - T::ONE) * (t - T::ONE));
+
+    let mut value = T::ZERO;
+    let mut gradient = Vector::<T, N>::ZERO;
+    for corner in 0..1usize << N {
+        let bit = |i: usize| corner >> i & 1 == 1;
+        let v = unit::<T>(hash_cell::<N>(array::from_fn(|i| cell[i].wrapping_add(bit(i) as i32))))
+            * T::from_f64(2.0)
+            - T::ONE;
+        let w: [T; N] = array::from_fn(|i| if bit(i) { u.0[i] } else { T::ONE - u.0[i] });
+        let dw: [T; N] = array::from_fn(|i| if bit(i) { du.0[i] } else { -du.0[i] });
+
+        value += v * w.iter().fold(T::ONE, |a, &b| a * b);
+        gradient += Vector(array::from_fn(|k| {
+            let rest = (0..N).filter(|&i| i != k).fold(T::ONE, |a, i| a * w[i]);
+            v * dw[k] * rest
+        }));
+    }
+    (value, gradient)
+}
+
+// The offset from the feature point of the cell at the given offset from
+// the cell of the position to the position.
+#[inline]
+fn feature_delta<T: Float, const N: usize>(cell: [i32; N], f: Vector<T, N>, offset: [i32; N]) -> Vector<T, N> {
+    let h = hash_cell::<N>(array::from_fn(|i| cell[i].wrapping_add(offset[i])));
+    Vector(array::from_fn(|i| {
+        let jitter = unit::<T>(hash(h ^ (i as u32 + 1).wrapping_mul(0x85ebca6b)));
+        f.0[i] - (T::from_f64(offset[i] as f64) + jitter)
+    }))
+}
+
+// Cells are visited nearest first, and skipped once they cannot hold a point
+// closer than the second nearest found so far. The cell of the position and
+// a face neighbour hold two points closer than sqrt(N + 3), and a cell four
+// away is at least 3 away, so three cells in every direction are enough.
+#[inline]
+fn worley_impl<T: Float + Cast<i32>, const N: usize>(p: Vector<T, N>) -> [(T, Vector<T, N>); 2] {
+    const OFFSETS: [i32; 7] = [0, -1, 1, -2, 2, -3, 3];
+
+    let (cell, f) = split(p);
+    let mut nearest = [(T::INFINITY, Vector::<T, N>::ZERO); 2];
+    for index in 0..OFFSETS.len().pow(N as u32) {
+        let offset: [i32; N] = array::from_fn(|i| OFFSETS[index / OFFSETS.len().pow(i as u32) % OFFSETS.len()]);
+
+        let mut bound = T::ZERO;
+        for (i, &o) in offset.iter().enumerate() {
+            let lo = T::from_f64(o as f64);
+            let hi = lo + T::ONE;
+            let d = if f.0[i] < lo {
+                lo - f.0[i]
+            } else if f.0[i] > hi {
+                f.0[i] - hi
+            } else {
+                T::ZERO
+            };
+            bound += d * d;
+        }
+        if bound >= nearest[1].0 {
+            continue;
+        }
+
+        let delta = feature_delta(cell, f, offset);
+        let d = delta.dot(delta);
+        if d < nearest[0].0 {
+            nearest[1] = nearest[0];
+            nearest[0] = (d, delta);
+        } else if d < nearest[1].0 {
+            nearest[1] = (d, delta);
+        }
+    }
+
+    nearest.map(|(d, delta)| {
+        let d = d.sqrt();
+        (d, if d > T::ZERO { delta / d } else { Vector::ZERO })
+    })
+}
+
+impl<T: Float + Cast<i32>> LatticeNoise for vec2<T> {
+    type Scalar = T;
+
+    #[inline]
+    fn value_noise_gradient(self) -> (T, Self) {
+        value_noise_impl(self)
+    }
+
+    #[inline]
+    fn worley_gradient(self) -> [(T, Self); 2] {
+        worley_impl(self)
+    }
+}
+
+impl<T: Float + Cast<i32>> LatticeNoise for vec3<T> {
+    type Scalar = T;
+
+    #[inline]
+    fn value_noise_gradient(self) -> (T, Self) {
+        value_noise_impl(self)
+    }
+
+    #[inline]
+    fn worley_gradient(self) -> [(T, Self); 2] {
+        worley_impl(self)
+    }
+}
+
+#[inline]
+pub fn value_noise<V: LatticeNoise>(position: V) -> V::Scalar {
+    position.value_noise_gradient().0
+}
+
+#[inline]
+pub fn value_noise_gradient<V: LatticeNoise>(position: V) -> (V::Scalar, V) {
+    position.value_noise_gradient()
+}
+
+#[inline]
+pub fn worley_f1<V: LatticeNoise>(position: V) -> V::Scalar {
+    position.worley_gradient()[0].0
+}
+
+#[inline]
+pub fn worley_f2<V: LatticeNoise>(position: V) -> V::Scalar {
+    position.worley_gradient()[1].0
+}
+
+#[inline]
+pub fn worley_f2_f1<V: LatticeNoise>(position: V) -> V::Scalar {
+    let [f1, f2] = position.worley_gradient();
+    f2.0 - f1.0
+}
+
+#[inline]
+pub fn worley_f1_gradient<V: LatticeNoise>(position: V) -> (V::Scalar, V) {
+    position.worley_gradient()[0]
+}
+
+#[inline]
+pub fn worley_f2_gradient<V: LatticeNoise>(position: V) -> (V::Scalar, V) {
+    position.worley_gradient()[1]
+}
+
+#[inline]
+pub fn worley_f2_f1_gradient<V: LatticeNoise>(position: V) -> (V::Scalar, V) {
+    let [f1, f2] = position.worley_gradient();
+    (f2.0 - f1.0, f2.1 - f1.1)
+}
+
+#[cfg(test)]
+mod tests {
+    use core::array;
+
+    use crate::{vec2, vec3, worley_f2_f1_gradient, LatticeNoise, Vector};
+
+    use super::{feature_delta, split};
+
+    // Every cell within four in every direction, without pruning.
+    fn brute_force<const N: usize>(p: Vector<f64, N>) -> [f64; 2] {
+        let (cell, f) = split(p);
+        let mut distances = [f64::INFINITY; 2];
+        for index in 0..9usize.pow(N as u32) {
+            let offset: [i32; N] = array::from_fn(|i| (index / 9usize.pow(i as u32) % 9) as i32 - 4);
+            let delta = feature_delta(cell, f, offset);
+            let d = delta.dot(delta).sqrt();
+            if d < distances[0] {
+                distances = [d, distances[0]];
+            } else if d < distances[1] {
+                distances[1] = d;
+            }
+        }
+        distances
+    }
+
+    #[test]
+    fn worley_matches_brute_force() {
+        for i in 0..400 {
+            let t = i as f64 * 0.7548776662466927;
+            let p = vec2::new(t * 3.1 - 50.0, (t * 1.37).fract() * 20.0 - 10.0);
+            assert_eq!(p.worley_gradient().map(|(d, _)| d), brute_force(p), "{p:?}");
+            let p = vec3::new(t * 3.1 - 50.0, (t * 1.37).fract() * 20.0 - 10.0, (t * 0.5698402909980532).fract() * 40.0);
+            assert_eq!(p.worley_gradient().map(|(d, _)| d), brute_force(p), "{p:?}");
+        }
+    }
+
+    // Pseudo-random points in [-50, 50) per component.
+    fn points<const N: usize>(count: usize) -> impl Iterator<Item = Vector<f64, N>> {
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        (0..count).map(move |_| {
+            Vector(array::from_fn(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                (state >> 11) as f64 / (1u64 << 53) as f64 * 100.0 - 50.0
+            }))
+        })
+    }
+
+    // Compares the analytic gradient with central differences, skipping the
+    // few components where the one sided differences disagree because a
+    // crease lies within the step. Returns how many were skipped.
+    fn check_gradient<const N: usize>(f: impl Fn(Vector<f64, N>) -> (f64, Vector<f64, N>), p: Vector<f64, N>) -> usize {
+        let h = 1e-6;
+        let (value, gradient) = f(p);
+        let mut skipped = 0;
+        for i in 0..N {
+            let mut step = Vector::<f64, N>::ZERO;
+            step.0[i] = h;
+            let (forward, backward) = (f(p + step).0, f(p - step).0);
+            if ((forward - value) - (value - backward)).abs() > 1e-8 {
+                skipped += 1;
+                continue;
+            }
+            let central = (forward - backward) / (2.0 * h);
+            assert!((central - gradient.0[i]).abs() < 1e-6, "{p:?} {i}: {central} != {}", gradient.0[i]);
+        }
+        skipped
+    }
+
+    #[test]
+    fn value_noise_gradient_matches_central_differences() {
+        for p in points::<2>(500) {
+            assert_eq!(check_gradient(|p: vec2<f64>| p.value_noise_gradient(), p), 0);
+        }
+        for p in points::<3>(500) {
+            assert_eq!(check_gradient(|p: vec3<f64>| p.value_noise_gradient(), p), 0);
+        }
+    }
+
+    #[test]
+    fn worley_gradients_match_central_differences() {
+        let mut skipped = 0;
+        for p in points::<2>(500) {
+            skipped += check_gradient(|p: vec2<f64>| p.worley_gradient()[0], p);
+            skipped += check_gradient(|p: vec2<f64>| p.worley_gradient()[1], p);
+            skipped += check_gradient(worley_f2_f1_gradient, p);
+        }
+        for p in points::<3>(500) {
+            skipped += check_gradient(|p: vec3<f64>| p.worley_gradient()[0], p);
+            skipped += check_gradient(|p: vec3<f64>| p.worley_gradient()[1], p);
+            skipped += check_gradient(worley_f2_f1_gradient, p);
+        }
+        assert!(skipped < 10, "{skipped}");
+    }
+}
@@ -0,0 +1,219 @@
+use core::{error, fmt};
+
+use crate::{mat3, mat4, quat, vec3, vec4, Float};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Decomposition<T> {
+    pub scale: vec3<T>,
+    pub orientation: quat<T>,
+    pub translation: vec3<T>,
+    pub skew: vec3<T>,
+    pub perspective: vec4<T>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DecomposeError {
+    // An element is infinite or NaN.
+    NonFinite,
+    // The bottom right element is zero, so the matrix cannot be normalized.
+    ZeroW,
+    // The upper 3x3 part is singular: a scale is zero or the axes are
+    // coplanar.
+    Singular,
+}
+
+impl fmt::Display for DecomposeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DecomposeError::NonFinite => "matrix has a non-finite element",
+            DecomposeError::ZeroW => "matrix has a zero w component",
+            DecomposeError::Singular => "matrix has a singular linear part",
+        })
+    }
+}
+
+impl error::Error for DecomposeError {}
+
+#[inline]
+fn determinant3<T: Float>(m: &mat4<T>) -> T {
+    let m = &m.value;
+    m[0].x * (m[1].y * m[2].z - m[2].y * m[1].z)
+        - m[1].x * (m[0].y * m[2].z - m[2].y * m[0].z)
+        + m[2].x * (m[0].y * m[1].z - m[1].y * m[0].z)
+}
+
+impl<T: Float> mat4<T> {
+    // After the Graphics Gems II unmatrix, like GLM: the matrix is
+    // perspective * translation * rotation * skew * scale, where skew adds
+    // z * skew.x to y, z * skew.y to x and y * skew.z to x. A negative
+    // determinant flips all three scales.
+    pub fn decompose(&self) -> Result<Decomposition<T>, DecomposeError> {
+        let mut local = *self;
+        // x * 0 is NaN for both infinities and NaN, which would otherwise
+        // slip through the comparisons below.
+        if !local.value.iter().all(|column| column.0.iter().all(|&x| x * T::ZERO == T::ZERO)) {
+            return Err(DecomposeError::NonFinite);
+        }
+        if local.value[3].w.abs() < T::EPSILON {
+            return Err(DecomposeError::ZeroW);
+        }
+        let one_over_w = T::ONE / local.value[3].w;
+        for column in &mut local.value {
+            *column *= one_over_w;
+        }
+
+        // The affine part, which the perspective is solved against.
+        let mut affine = local;
+        for column in &mut affine.value[..3] {
+            column.w = T::ZERO;
+        }
+        affine.value[3].w = T::ONE;
+        // Relative to the product of the column lengths, which bounds the
+        // determinant, so that the test does not depend on the scale.
+        let lengths = affine.value[..3].iter().fold(T::ONE, |product, c| product * vec3::new(c.x, c.y, c.z).length());
+        if determinant3(&affine).abs() <= lengths * T::EPSILON {
+            return Err(DecomposeError::Singular);
+        }
+
+        let perspective = if local.value[0].w != T::ZERO || local.value[1].w != T::ZERO || local.value[2].w != T::ZERO {
+            let right_hand_side = vec4::new(local.value[0].w, local.value[1].w, local.value[2].w, local.value[3].w);
+            affine.inverse().transpose() * right_hand_side
+        } else {
+            vec4::W
+        };
+
+        let translation = vec3::new(affine.value[3].x, affine.value[3].y, affine.value[3].z);
+
+        let mut row = [0, 1, 2].map(|i| vec3::new(affine.value[i].x, affine.value[i].y, affine.value[i].z));
+        let mut scale = vec3::splat(T::ZERO);
+        let mut skew = vec3::splat(T::ZERO);
+
+        // Gram-Schmidt, keeping the projections as skew.
+        scale.x = row[0].length();
+        row[0] /= scale.x;
+
+        skew.z = row[0].dot(row[1]);
+        row[1] -= row[0] * skew.z;
+        scale.y = row[1].length();
+        row[1] /= scale.y;
+        skew.z /= scale.y;
+
+        skew.y = row[0].dot(row[2]);
+        row[2] -= row[0] * skew.y;
+        skew.x = row[1].dot(row[2]);
+        row[2] -= row[1] * skew.x;
+        scale.z = row[2].length();
+        row[2] /= scale.z;
+        skew.y /= scale.z;
+        skew.x /= scale.z;
+
+        if row[0].dot(row[1].cross(row[2])) < T::ZERO {
+            scale = -scale;
+            row = row.map(|r| -r);
+        }
+
+        let orientation = quat::from(mat3::new(row[0], row[1], row[2]));
+        Ok(Decomposition { scale, orientation, translation, skew, perspective })
+    }
+
+    pub fn recompose(decomposition: &Decomposition<T>) -> Self {
+        let Decomposition { scale, orientation, translation, skew, perspective } = *decomposition;
+        let rotation = mat3::from(orientation);
+        let skew = mat3::new(
+            vec3::new(T::ONE, T::ZERO, T::ZERO),
+            vec3::new(skew.z, T::ONE, T::ZERO),
+            vec3::new(skew.y, skew.x, T::ONE));
+        let linear = rotation * skew;
+
+        let mut projection = mat4::identity();
+        projection.value[0].w = perspective.x;
+        projection.value[1].w = perspective.y;
+        projection.value[2].w = perspective.z;
+        projection.value[3].w = perspective.w;
+
+        let affine = mat4::new(
+            vec4::new(linear.value[0].x * scale.x, linear.value[0].y * scale.x, linear.value[0].z * scale.x, T::ZERO),
+            vec4::new(linear.value[1].x * scale.y, linear.value[1].y * scale.y, linear.value[1].z * scale.y, T::ZERO),
+            vec4::new(linear.value[2].x * scale.z, linear.value[2].y * scale.z, linear.value[2].z * scale.z, T::ZERO),
+            vec4::new(translation.x, translation.y, translation.z, T::ONE));
+        projection * affine
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{mat4, quat, vec3, vec4, DecomposeError, Decomposition};
+
+    fn close(a: mat4, b: mat4, tolerance: f32) -> bool {
+        a.value.iter().zip(b.value.iter()).all(|(a, b)| (*a - *b).0.iter().all(|x| x.abs() <= tolerance))
+    }
+
+    fn decomposition(scale: vec3) -> Decomposition<f32> {
+        Decomposition {
+            scale,
+            orientation: quat::new(0.3, -0.5, 0.2, 0.8).normalize(),
+            translation: vec3::new(4.0, -5.0, 6.0),
+            skew: vec3::new(0.25, -0.5, 0.125),
+            perspective: vec4::new(0.0, 0.0, 0.0, 1.0),
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        let mut expected = decomposition(vec3::new(2.0, 0.5, 3.0));
+        let m = mat4::recompose(&expected);
+        let actual = m.decompose().unwrap();
+        assert!((actual.scale - expected.scale).0.iter().all(|x| x.abs() <= 1e-5), "{actual:?}");
+        assert!((actual.skew - expected.skew).0.iter().all(|x| x.abs() <= 1e-5), "{actual:?}");
+        assert!((actual.translation - expected.translation).0.iter().all(|x| x.abs() <= 1e-5), "{actual:?}");
+        assert!(actual.orientation.dot(expected.orientation).abs() >= 1.0 - 1e-6, "{actual:?}");
+        assert_eq!(actual.perspective, vec4::W);
+
+        // A mirrored matrix comes back with all three scales flipped, and
+        // one with a perspective comes back divided by its w.
+        expected.scale = vec3::new(2.0, -0.5, 3.0);
+        expected.perspective = vec4::new(0.1, -0.2, 0.05, 1.0);
+        let m = mat4::recompose(&expected);
+        let actual = m.decompose().unwrap();
+        assert!(actual.scale.0.iter().all(|&x| x < 0.0), "{actual:?}");
+        assert!(close(mat4::recompose(&actual), m * (1.0 / m.value[3].w), 1e-5));
+    }
+
+    #[test]
+    fn small_scales_are_not_singular() {
+        let m = mat4::recompose(&decomposition(vec3::splat(1e-4)));
+        let actual = m.decompose().unwrap();
+        assert!((actual.scale - vec3::splat(1e-4)).0.iter().all(|x| x.abs() <= 1e-9), "{actual:?}");
+        assert!(close(mat4::recompose(&actual), m, 1e-9));
+    }
+
+    #[test]
+    fn degenerate_input_is_an_error() {
+        let mut m = mat4::recompose(&decomposition(vec3::new(2.0, 0.0, 3.0)));
+        assert_eq!(m.decompose(), Err(DecomposeError::Singular));
+
+        // Coplanar axes, whatever the scale.
+        for scale in [1e-6, 1.0, 1e6] {
+            m = mat4::identity() * scale;
+            m.value[3].w = 1.0;
+            m.value[2] = vec4::new(scale, scale, 0.0, 0.0);
+            assert_eq!(m.decompose(), Err(DecomposeError::Singular));
+        }
+
+        m = mat4::identity();
+        m.value[3].w = 0.0;
+        assert_eq!(m.decompose(), Err(DecomposeError::ZeroW));
+        assert_eq!((mat4::identity() * 0.0).decompose(), Err(DecomposeError::ZeroW));
+    }
+
+    #[test]
+    fn non_finite_input_is_an_error() {
+        for x in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+            for (column, row) in [(0, 0), (1, 2), (2, 3), (3, 0), (3, 3)] {
+                let mut m = mat4::recompose(&decomposition(vec3::new(2.0, 0.5, 3.0)));
+                m.value[column][row] = x;
+                assert_eq!(m.decompose(), Err(DecomposeError::NonFinite));
+            }
+        }
+    }
+}
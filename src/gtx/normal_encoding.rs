@@ -0,0 +1,173 @@
+use crate::{pack_snorm_2x16, pack_unorm_2x16, unpack_snorm_2x16, unpack_unorm_2x16, vec2, vec3, Float};
+
+#[inline]
+fn sign_not_zero<T: Float>(x: T) -> T {
+    if x >= T::ZERO { T::ONE } else { -T::ONE }
+}
+
+// Octahedral mapping of a unit vector onto [-1, 1]^2. In f32 the round trip
+// stays within 0.00003 degrees of the input.
+#[inline]
+pub fn octahedral_encode<T: Float>(n: vec3<T>) -> vec2<T> {
+    let n = n * (T::ONE / (n.x.abs() + n.y.abs() + n.z.abs()));
+    if n.z >= T::ZERO {
+        vec2::new(n.x, n.y)
+    } else {
+        vec2::new(
+            (T::ONE - n.y.abs()) * sign_not_zero(n.x),
+            (T::ONE - n.x.abs()) * sign_not_zero(n.y))
+    }
+}
+
+#[inline]
+pub fn octahedral_decode<T: Float>(e: vec2<T>) -> vec3<T> {
+    let z = T::ONE - e.x.abs() - e.y.abs();
+    let v = if z >= T::ZERO {
+        vec3::new(e.x, e.y, z)
+    } else {
+        vec3::new(
+            (T::ONE - e.y.abs()) * sign_not_zero(e.x),
+            (T::ONE - e.x.abs()) * sign_not_zero(e.y),
+            z)
+    };
+    v.normalize()
+}
+
+// With 16 bits per component the round trip stays within 0.004 degrees.
+#[inline]
+pub fn pack_octahedral_snorm_2x16(n: vec3) -> u32 {
+    pack_snorm_2x16(octahedral_encode(n))
+}
+
+#[inline]
+pub fn unpack_octahedral_snorm_2x16(p: u32) -> vec3 {
+    octahedral_decode(unpack_snorm_2x16(p))
+}
+
+// Same bound as the snorm variant, with [-1, 1] remapped onto [0, 1].
+#[inline]
+pub fn pack_octahedral_unorm_2x16(n: vec3) -> u32 {
+    pack_unorm_2x16(octahedral_encode(n) * 0.5 + 0.5)
+}
+
+#[inline]
+pub fn unpack_octahedral_unorm_2x16(p: u32) -> vec3 {
+    octahedral_decode(unpack_unorm_2x16(p) * 2.0 - 1.0)
+}
+
+// Lambert azimuthal projection onto [0, 1]^2. In f32 the round trip stays
+// within 0.01 degrees for z > -0.99, degrades to several degrees closer to
+// -z and is undefined at exactly -z.
+#[inline]
+pub fn spheremap_encode<T: Float>(n: vec3<T>) -> vec2<T> {
+    let two = T::ONE + T::ONE;
+    let eight = two * two * two;
+    let f = (eight * n.z + eight).sqrt();
+    vec2::new(n.x / f + T::ONE / two, n.y / f + T::ONE / two)
+}
+
+#[inline]
+pub fn spheremap_decode<T: Float>(e: vec2<T>) -> vec3<T> {
+    let two = T::ONE + T::ONE;
+    let four = two * two;
+    let e = e * four - two;
+    let f = e.dot(e);
+    let g = (T::ONE - f / four).sqrt();
+    vec3::new(e.x * g, e.y * g, T::ONE - f / two)
+}
+
+// Projection from -z onto the z = 0 plane, mapping the +z hemisphere inside
+// the unit disk. In f32 the round trip stays within 0.0001 degrees for
+// z > -0.99 and 0.003 degrees elsewhere, and is undefined at exactly -z.
+#[inline]
+pub fn stereographic_encode<T: Float>(n: vec3<T>) -> vec2<T> {
+    let scale = T::ONE / (T::ONE + n.z);
+    vec2::new(n.x * scale, n.y * scale)
+}
+
+#[inline]
+pub fn stereographic_decode<T: Float>(e: vec2<T>) -> vec3<T> {
+    let two = T::ONE + T::ONE;
+    let d = e.dot(e);
+    let scale = T::ONE / (T::ONE + d);
+    vec3::new(two * e.x * scale, two * e.y * scale, (T::ONE - d) * scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::PI;
+
+    use crate::{
+        octahedral_decode, octahedral_encode, pack_octahedral_snorm_2x16, pack_octahedral_unorm_2x16,
+        spheremap_decode, spheremap_encode, stereographic_decode, stereographic_encode,
+        unpack_octahedral_snorm_2x16, unpack_octahedral_unorm_2x16, vec3, Float,
+    };
+
+    // In degrees, computed in f64 from the cross and dot products, which
+    // stays accurate for tiny angles unlike acos.
+    fn angle(a: vec3, b: vec3) -> f64 {
+        let (a, b) = (a.map(|x| x as f64), b.map(|x| x as f64));
+        Float::atan2(a.cross(b).length(), a.dot(b)) * 180.0 / PI
+    }
+
+    // A Fibonacci sphere, the poles and axes, and points on and just off
+    // the octahedral seams: the equator, where the lower hemisphere folds
+    // over, and the x = 0 and y = 0 lines of the lower hemisphere, across
+    // which the folded halves meet.
+    fn unit_vectors() -> impl Iterator<Item = vec3> {
+        let count = 20000;
+        let golden = PI * (3.0 - Float::sqrt(5.0));
+        let sphere = (0..count).map(move |i| {
+            let z = 1.0 - (2 * i + 1) as f64 / count as f64;
+            let r = Float::sqrt(1.0 - z * z);
+            let phi = golden * i as f64;
+            vec3::new((Float::cos(phi) * r) as f32, (Float::sin(phi) * r) as f32, z as f32)
+        });
+        let axes = [
+            vec3::new(1.0, 0.0, 0.0), vec3::new(-1.0, 0.0, 0.0),
+            vec3::new(0.0, 1.0, 0.0), vec3::new(0.0, -1.0, 0.0),
+            vec3::new(0.0, 0.0, 1.0), vec3::new(0.0, 0.0, -1.0),
+        ];
+        let seams = (0..=360).flat_map(|i| {
+            let t = (i as f64 * PI / 180.0) as f32;
+            let (s, c) = (Float::sin(t), Float::cos(t));
+            [-1e-4, -1e-7, 0.0, 1e-7, 1e-4].into_iter().flat_map(move |d: f32| {
+                [vec3::new(c, s, d), vec3::new(d, c, -s.abs()), vec3::new(c, d, -s.abs())]
+            })
+        });
+        sphere.chain(axes).chain(seams).map(|n| n.normalize())
+    }
+
+    fn max_error(encode: impl Fn(vec3) -> vec3, filter: impl Fn(vec3) -> bool) -> f64 {
+        unit_vectors().filter(|&n| filter(n)).map(|n| angle(n, encode(n))).fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn octahedral_error() {
+        assert!(max_error(|n| octahedral_decode(octahedral_encode(n)), |_| true) <= 0.00003);
+        assert!(max_error(|n| unpack_octahedral_snorm_2x16(pack_octahedral_snorm_2x16(n)), |_| true) <= 0.004);
+        assert!(max_error(|n| unpack_octahedral_unorm_2x16(pack_octahedral_unorm_2x16(n)), |_| true) <= 0.004);
+    }
+
+    // Not with unorm, where 0 falls between two steps.
+    #[test]
+    fn octahedral_axes_are_exact() {
+        for n in unit_vectors().filter(|n| n.0.iter().filter(|&&x| x == 0.0).count() == 2) {
+            assert_eq!(octahedral_decode(octahedral_encode(n)), n);
+            assert_eq!(unpack_octahedral_snorm_2x16(pack_octahedral_snorm_2x16(n)), n);
+        }
+    }
+
+    #[test]
+    fn spheremap_error() {
+        assert!(max_error(|n| spheremap_decode(spheremap_encode(n)), |n| n.z > -0.99) <= 0.01);
+        assert_eq!(spheremap_decode(spheremap_encode(vec3::new(0.0, 0.0, 1.0))), vec3::new(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn stereographic_error() {
+        assert!(max_error(|n| stereographic_decode(stereographic_encode(n)), |n| n.z > -0.99) <= 0.0001);
+        assert!(max_error(|n| stereographic_decode(stereographic_encode(n)), |n| n.z > -1.0) <= 0.003);
+        assert_eq!(stereographic_decode(stereographic_encode(vec3::new(0.0, 0.0, 1.0))), vec3::new(0.0, 0.0, 1.0));
+    }
+}
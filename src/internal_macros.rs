@@ -2,7 +2,10 @@
 
 macro_rules! forward_ref_unop {
     (impl $imp:ident, $method:ident for $t:ty) => {
-        impl $imp for &$t {
+        forward_ref_unop! { impl[] $imp, $method for $t }
+    };
+    (impl[$($g:tt)*] $imp:ident, $method:ident for $t:ty) => {
+        impl<$($g)*> $imp for &$t {
             type Output = <$t as $imp>::Output;
 
             #[inline]
@@ -15,7 +18,10 @@ macro_rules! forward_ref_unop {
 
 macro_rules! forward_ref_binop {
     (impl $imp:ident, $method:ident for $t:ty, $u:ty) => {
-        impl<'a> $imp<$u> for &'a $t {
+        forward_ref_binop! { impl[] $imp, $method for $t, $u }
+    };
+    (impl[$($g:tt)*] $imp:ident, $method:ident for $t:ty, $u:ty) => {
+        impl<'a, $($g)*> $imp<$u> for &'a $t {
             type Output = <$t as $imp<$u>>::Output;
 
             #[inline]
@@ -24,7 +30,7 @@ macro_rules! forward_ref_binop {
             }
         }
 
-        impl $imp<&$u> for $t {
+        impl<$($g)*> $imp<&$u> for $t {
             type Output = <$t as $imp<$u>>::Output;
 
             #[inline]
@@ -33,7 +39,7 @@ macro_rules! forward_ref_binop {
             }
         }
 
-        impl $imp<&$u> for &$t {
+        impl<$($g)*> $imp<&$u> for &$t {
             type Output = <$t as $imp<$u>>::Output;
 
             #[inline]
@@ -46,7 +52,10 @@ macro_rules! forward_ref_binop {
 
 macro_rules! forward_ref_op_assign {
     (impl $imp:ident, $method:ident for $t:ty, $u:ty) => {
-        impl $imp<&$u> for $t {
+        forward_ref_op_assign! { impl[] $imp, $method for $t, $u }
+    };
+    (impl[$($g:tt)*] $imp:ident, $method:ident for $t:ty, $u:ty) => {
+        impl<$($g)*> $imp<&$u> for $t {
             #[inline]
             fn $method(&mut self, other: &$u) {
                 $imp::$method(self, *other);
@@ -1,25 +1,110 @@
+#![no_std]
+#![allow(clippy::len_without_is_empty, clippy::let_and_return)]
+
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("either the `std` or the `libm` feature must be enabled");
+
 #[macro_use]
 mod internal_macros;
 
-mod func_common;
+mod scalar;
 mod func_geometric;
+mod func_integer;
 mod func_matrix;
+mod func_packing;
+mod type_half;
+mod type_vector;
 mod type_vec1;
 mod type_vec2;
 mod type_vec3;
 mod type_vec4;
+mod type_vec_batch;
+mod type_matrix;
 mod type_mat2x2;
+mod type_mat2x3;
+mod type_mat2x4;
+mod type_mat3x2;
 mod type_mat3x3;
+mod type_mat3x4;
+mod type_mat4x2;
+mod type_mat4x3;
 mod type_mat4x4;
+mod type_quat;
+mod type_primitive;
+
+pub mod swizzle;
 
+pub use scalar::*;
+pub use func_integer::*;
+pub use func_packing::*;
+pub use type_half::*;
+pub use type_vector::*;
 pub use type_vec1::*;
 pub use type_vec2::*;
 pub use type_vec3::*;
 pub use type_vec4::*;
+pub use type_vec_batch::*;
+pub use type_matrix::*;
 pub use type_mat2x2::*;
+pub use type_mat2x3::*;
+pub use type_mat2x4::*;
+pub use type_mat3x2::*;
 pub use type_mat3x3::*;
+pub use type_mat3x4::*;
+pub use type_mat4x2::*;
+pub use type_mat4x3::*;
 pub use type_mat4x4::*;
+pub use type_quat::*;
+pub use type_primitive::*;
+pub use gtc::bitfield::*;
+pub use gtc::color_space::*;
+pub use gtc::constants::*;
+pub use gtc::noise::*;
+pub use gtc::packing::*;
+pub use gtc::random::*;
+pub use gtc::round::*;
+pub use gtx::color_encoding::*;
+pub use gtx::color_perceptual::*;
+pub use gtx::color_space::*;
+pub use gtx::color_space_ycocg::*;
+pub use gtx::intersect::*;
+pub use gtx::lattice_noise::*;
+pub use gtx::matrix_decompose::*;
+pub use gtx::normal_encoding::*;
+pub use ext::matrix_frustum::*;
+pub use ext::matrix_projection::*;
+pub use ext::scalar_integer::*;
+pub use ext::scalar_ulp::*;
+
+mod gtc {
+    pub mod bitfield;
+    pub mod color_space;
+    pub mod constants;
+    pub mod noise;
+    pub mod packing;
+    pub mod random;
+    pub mod round;
+}
+
+mod gtx {
+    pub mod color_encoding;
+    pub mod color_perceptual;
+    pub mod color_space;
+    pub mod color_space_ycocg;
+    mod euler_angles;
+    pub mod intersect;
+    pub mod lattice_noise;
+    pub mod matrix_decompose;
+    pub mod normal_encoding;
+}
 
 mod ext {
     mod matrix_clip_space;
+    pub mod matrix_frustum;
+    pub mod matrix_projection;
+    pub mod scalar_integer;
+    pub mod scalar_ulp;
 }
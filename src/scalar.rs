@@ -0,0 +1,338 @@
+use core::{fmt::Debug, hash::Hash, num::{Saturating, Wrapping}, ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign, Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign}};
+
+pub trait Scalar: Copy + Debug + Default + PartialEq + 'static {}
+
+pub trait Number:
+    Scalar
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + RemAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+pub trait SignedNumber: Number + Neg<Output = Self> {}
+
+pub trait Bounded: Scalar {
+    const MIN: Self;
+    const MAX: Self;
+}
+
+pub trait Integer:
+    Number
+    + Bounded
+    + Eq
+    + Ord
+    + Hash
+    + Not<Output = Self>
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Shl<u32, Output = Self>
+    + Shr<u32, Output = Self>
+    + BitAndAssign
+    + BitOrAssign
+    + BitXorAssign
+    + ShlAssign<u32>
+    + ShrAssign<u32>
+{
+    const BITS: u32;
+
+    fn count_ones(self) -> u32;
+    fn leading_zeros(self) -> u32;
+    fn trailing_zeros(self) -> u32;
+    fn reverse_bits(self) -> Self;
+    fn rotate_left(self, n: u32) -> Self;
+    fn rotate_right(self, n: u32) -> Self;
+
+    fn wrapping_add(self, other: Self) -> Self;
+    fn wrapping_sub(self, other: Self) -> Self;
+    fn wrapping_mul(self, other: Self) -> Self;
+    fn wrapping_div(self, other: Self) -> Self;
+    fn wrapping_rem(self, other: Self) -> Self;
+    fn wrapping_neg(self) -> Self;
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_div(self, other: Self) -> Option<Self>;
+    fn checked_rem(self, other: Self) -> Option<Self>;
+    fn checked_neg(self) -> Option<Self>;
+    fn saturating_add(self, other: Self) -> Self;
+    fn saturating_sub(self, other: Self) -> Self;
+    fn saturating_mul(self, other: Self) -> Self;
+    fn saturating_div(self, other: Self) -> Self;
+    fn overflowing_add(self, other: Self) -> (Self, bool);
+    fn overflowing_sub(self, other: Self) -> (Self, bool);
+    fn overflowing_mul(self, other: Self) -> (Self, bool);
+    fn overflowing_div(self, other: Self) -> (Self, bool);
+    fn overflowing_rem(self, other: Self) -> (Self, bool);
+    fn overflowing_neg(self) -> (Self, bool);
+}
+
+pub trait Float: SignedNumber + Bounded {
+    const EPSILON: Self;
+    const NAN: Self;
+    const INFINITY: Self;
+    const NEG_INFINITY: Self;
+
+    fn from_f64(value: f64) -> Self;
+
+    fn abs(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn trunc(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn exp(self) -> Self;
+    fn exp2(self) -> Self;
+    fn ln(self) -> Self;
+    fn log2(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+}
+
+pub trait Cast<T>: Scalar {
+    fn cast(self) -> T;
+}
+
+impl Scalar for bool {}
+
+macro_rules! number_impl {
+    ($zero:literal, $one:literal, $($t:ty)*) => ($(
+        impl Scalar for $t {}
+
+        impl Number for $t {
+            const ZERO: Self = $zero;
+            const ONE: Self = $one;
+        }
+    )*)
+}
+
+number_impl! { 0, 1, usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+number_impl! { 0.0, 1.0, f32 f64 }
+
+macro_rules! signed_number_impl {
+    ($($t:ty)*) => ($(
+        impl SignedNumber for $t {}
+    )*)
+}
+
+signed_number_impl! { isize i8 i16 i32 i64 i128 f32 f64 }
+
+// `Wrapping` and `Saturating` elements give vectors the same overflow
+// behavior as the scalar wrappers.
+macro_rules! wrapper_number_impl {
+    ($($t:ty)*) => ($(
+        wrapper_number_impl! { @ Wrapping<$t> }
+        wrapper_number_impl! { @ Saturating<$t> }
+    )*);
+    (@ $t:ty) => {
+        impl Scalar for $t {}
+
+        impl Number for $t {
+            const ZERO: Self = Self(0);
+            const ONE: Self = Self(1);
+        }
+    }
+}
+
+wrapper_number_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+
+macro_rules! wrapper_signed_number_impl {
+    ($($t:ty)*) => ($(
+        impl SignedNumber for Wrapping<$t> {}
+        impl SignedNumber for Saturating<$t> {}
+    )*)
+}
+
+wrapper_signed_number_impl! { isize i8 i16 i32 i64 i128 }
+
+macro_rules! cast_impl {
+    ($($t:ty)*) => ($(
+        cast_impl! { @ $t, usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
+    )*);
+    (@ $t:ty, $($u:ty)*) => ($(
+        impl Cast<$u> for $t {
+            #[inline]
+            fn cast(self) -> $u {
+                self as $u
+            }
+        }
+    )*)
+}
+
+cast_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
+
+macro_rules! bounded_impl {
+    ($($t:ty)*) => ($(
+        impl Bounded for $t {
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+        }
+    )*)
+}
+
+bounded_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
+
+macro_rules! integer_impl {
+    ($($t:ty)*) => ($(
+        impl Integer for $t {
+            const BITS: u32 = <$t>::BITS;
+
+            #[inline]
+            fn count_ones(self) -> u32 {
+                <$t>::count_ones(self)
+            }
+
+            #[inline]
+            fn leading_zeros(self) -> u32 {
+                <$t>::leading_zeros(self)
+            }
+
+            #[inline]
+            fn trailing_zeros(self) -> u32 {
+                <$t>::trailing_zeros(self)
+            }
+
+            #[inline]
+            fn reverse_bits(self) -> Self {
+                <$t>::reverse_bits(self)
+            }
+
+            #[inline]
+            fn rotate_left(self, n: u32) -> Self {
+                <$t>::rotate_left(self, n)
+            }
+
+            #[inline]
+            fn rotate_right(self, n: u32) -> Self {
+                <$t>::rotate_right(self, n)
+            }
+
+            integer_arith_impl! { $t,
+                wrapping_add(other) -> Self;
+                wrapping_sub(other) -> Self;
+                wrapping_mul(other) -> Self;
+                wrapping_div(other) -> Self;
+                wrapping_rem(other) -> Self;
+                wrapping_neg() -> Self;
+                checked_add(other) -> Option<Self>;
+                checked_sub(other) -> Option<Self>;
+                checked_mul(other) -> Option<Self>;
+                checked_div(other) -> Option<Self>;
+                checked_rem(other) -> Option<Self>;
+                checked_neg() -> Option<Self>;
+                saturating_add(other) -> Self;
+                saturating_sub(other) -> Self;
+                saturating_mul(other) -> Self;
+                saturating_div(other) -> Self;
+                overflowing_add(other) -> (Self, bool);
+                overflowing_sub(other) -> (Self, bool);
+                overflowing_mul(other) -> (Self, bool);
+                overflowing_div(other) -> (Self, bool);
+                overflowing_rem(other) -> (Self, bool);
+                overflowing_neg() -> (Self, bool);
+            }
+        }
+    )*)
+}
+
+macro_rules! integer_arith_impl {
+    ($t:ty, $($method:ident($($arg:ident),*) -> $output:ty;)*) => ($(
+        #[inline]
+        fn $method(self, $($arg: Self),*) -> $output {
+            <$t>::$method(self, $($arg),*)
+        }
+    )*)
+}
+
+integer_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+
+// With `std` the inherent float methods are used; otherwise the same
+// operations are routed to `libm`, and one of the two is required.
+macro_rules! float_impl {
+    ($t:ty, $($method:ident: $libm:ident($($arg:ident),*);)*) => {
+        impl Float for $t {
+            const EPSILON: Self = <$t>::EPSILON;
+            const NAN: Self = <$t>::NAN;
+            const INFINITY: Self = <$t>::INFINITY;
+            const NEG_INFINITY: Self = <$t>::NEG_INFINITY;
+
+            #[inline]
+            fn from_f64(value: f64) -> Self {
+                value as $t
+            }
+
+            $(
+                #[inline]
+                fn $method(self, $($arg: Self),*) -> Self {
+                    #[cfg(feature = "std")]
+                    let result = <$t>::$method(self, $($arg),*);
+                    #[cfg(all(not(feature = "std"), feature = "libm"))]
+                    let result = libm::$libm(self, $($arg),*);
+                    // Only the compile_error! in lib.rs is reported then.
+                    #[cfg(not(any(feature = "std", feature = "libm")))]
+                    let result = { $(let _ = $arg;)* self };
+                    result
+                }
+            )*
+        }
+    }
+}
+
+float_impl! { f32,
+    abs: fabsf();
+    floor: floorf();
+    ceil: ceilf();
+    round: roundf();
+    trunc: truncf();
+    sqrt: sqrtf();
+    exp: expf();
+    exp2: exp2f();
+    ln: logf();
+    log2: log2f();
+    powf: powf(n);
+    sin: sinf();
+    cos: cosf();
+    tan: tanf();
+    asin: asinf();
+    acos: acosf();
+    atan: atanf();
+    atan2: atan2f(other);
+}
+
+float_impl! { f64,
+    abs: fabs();
+    floor: floor();
+    ceil: ceil();
+    round: round();
+    trunc: trunc();
+    sqrt: sqrt();
+    exp: exp();
+    exp2: exp2();
+    ln: log();
+    log2: log2();
+    powf: pow(n);
+    sin: sin();
+    cos: cos();
+    tan: tan();
+    asin: asin();
+    acos: acos();
+    atan: atan();
+    atan2: atan2(other);
+}
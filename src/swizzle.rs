@@ -0,0 +1,36 @@
+use core::{ops::{Deref, DerefMut}, slice};
+
+// The named field views that vectors dereference to, so that v.x, v.y, v.z
+// and v.w read and write the components in place. They are not meant to be
+// built directly and are left out of the crate root.
+
+macro_rules! view {
+    ($name:ident, $len:literal, $($field:ident)*) => {
+        #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+        #[repr(C)]
+        pub struct $name<T> {
+            $(pub $field: T,)*
+        }
+
+        impl<T> Deref for $name<T> {
+            type Target = [T];
+
+            #[inline]
+            fn deref(&self) -> &[T] {
+                unsafe { slice::from_raw_parts(&raw const *self as *const T, $len) }
+            }
+        }
+
+        impl<T> DerefMut for $name<T> {
+            #[inline]
+            fn deref_mut(&mut self) -> &mut [T] {
+                unsafe { slice::from_raw_parts_mut(&raw mut *self as *mut T, $len) }
+            }
+        }
+    }
+}
+
+view! { X, 1, x }
+view! { XY, 2, x y }
+view! { XYZ, 3, x y z }
+view! { XYZW, 4, x y z w }
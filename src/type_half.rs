@@ -0,0 +1,344 @@
+use core::{cmp::Ordering, fmt, ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign}};
+
+use crate::{Bounded, Float, Number, Scalar, SignedNumber};
+
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct half(u16);
+
+impl half {
+    pub const ZERO: half = half(0x0000);
+    pub const ONE: half = half(0x3c00);
+    pub const MIN: half = half(0xfbff);
+    pub const MAX: half = half(0x7bff);
+    pub const MIN_POSITIVE: half = half(0x0400);
+    pub const EPSILON: half = half(0x1400);
+    pub const INFINITY: half = half(0x7c00);
+    pub const NEG_INFINITY: half = half(0xfc00);
+    pub const NAN: half = half(0x7e00);
+
+    #[inline]
+    pub const fn from_bits(bits: u16) -> Self {
+        Self(bits)
+    }
+
+    #[inline]
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    #[inline]
+    pub const fn from_f32(value: f32) -> Self {
+        let bits = value.to_bits();
+        let sign = ((bits >> 16) & 0x8000) as u16;
+        let exponent = ((bits >> 23) & 0xff) as i32;
+        let mantissa = bits & 0x007f_ffff;
+
+        if exponent == 0xff {
+            // Keep NaNs quiet and non-zero after the mantissa is truncated.
+            return if mantissa == 0 {
+                Self(sign | 0x7c00)
+            } else {
+                Self(sign | 0x7e00 | (mantissa >> 13) as u16)
+            };
+        }
+
+        let exponent = exponent - 127 + 15;
+        if exponent >= 0x1f {
+            return Self(sign | 0x7c00);
+        }
+
+        if exponent <= 0 {
+            if exponent < -10 {
+                return Self(sign);
+            }
+            let mantissa = mantissa | 0x0080_0000;
+            let shift = (14 - exponent) as u32;
+            let result = mantissa >> shift;
+            let remainder = mantissa & ((1 << shift) - 1);
+            let halfway = 1 << (shift - 1);
+            let round = (remainder > halfway || (remainder == halfway && result & 1 == 1)) as u32;
+            return Self(sign | (result + round) as u16);
+        }
+
+        let result = ((exponent as u32) << 10) | (mantissa >> 13);
+        let remainder = mantissa & 0x1fff;
+        // A carry out of the mantissa correctly bumps the exponent, up to infinity.
+        let round = (remainder > 0x1000 || (remainder == 0x1000 && result & 1 == 1)) as u32;
+        Self(sign | (result + round) as u16)
+    }
+
+    #[inline]
+    pub const fn to_f32(self) -> f32 {
+        let sign = ((self.0 & 0x8000) as u32) << 16;
+        let exponent = ((self.0 >> 10) & 0x1f) as u32;
+        let mantissa = (self.0 & 0x03ff) as u32;
+
+        let bits = if exponent == 0 {
+            if mantissa == 0 {
+                sign
+            } else {
+                let mut exponent = 127 - 15 + 1;
+                let mut mantissa = mantissa;
+                while mantissa & 0x0400 == 0 {
+                    mantissa <<= 1;
+                    exponent -= 1;
+                }
+                sign | (exponent << 23) | ((mantissa & 0x03ff) << 13)
+            }
+        } else if exponent == 0x1f {
+            sign | 0x7f80_0000 | (mantissa << 13)
+        } else {
+            sign | ((exponent + 127 - 15) << 23) | (mantissa << 13)
+        };
+        f32::from_bits(bits)
+    }
+
+    // Rounds once from the f64 bits, as going through f32 would round twice.
+    #[inline]
+    pub const fn from_f64(value: f64) -> Self {
+        let bits = value.to_bits();
+        let sign = ((bits >> 48) & 0x8000) as u16;
+        let exponent = ((bits >> 52) & 0x7ff) as i32;
+        let mantissa = bits & 0x000f_ffff_ffff_ffff;
+
+        if exponent == 0x7ff {
+            return if mantissa == 0 {
+                Self(sign | 0x7c00)
+            } else {
+                Self(sign | 0x7e00 | (mantissa >> 42) as u16)
+            };
+        }
+
+        let exponent = exponent - 1023 + 15;
+        if exponent >= 0x1f {
+            return Self(sign | 0x7c00);
+        }
+
+        if exponent <= 0 {
+            if exponent < -10 {
+                return Self(sign);
+            }
+            let mantissa = mantissa | 0x0010_0000_0000_0000;
+            let shift = (43 - exponent) as u32;
+            let result = mantissa >> shift;
+            let remainder = mantissa & ((1 << shift) - 1);
+            let halfway = 1 << (shift - 1);
+            let round = (remainder > halfway || (remainder == halfway && result & 1 == 1)) as u64;
+            return Self(sign | (result + round) as u16);
+        }
+
+        let result = ((exponent as u64) << 10) | (mantissa >> 42);
+        let remainder = mantissa & 0x03ff_ffff_ffff;
+        let round = (remainder > 0x0200_0000_0000 || (remainder == 0x0200_0000_0000 && result & 1 == 1)) as u64;
+        Self(sign | (result + round) as u16)
+    }
+
+    #[inline]
+    pub const fn to_f64(self) -> f64 {
+        self.to_f32() as f64
+    }
+
+    #[inline]
+    pub const fn is_nan(self) -> bool {
+        self.0 & 0x7c00 == 0x7c00 && self.0 & 0x03ff != 0
+    }
+
+    #[inline]
+    pub const fn is_infinite(self) -> bool {
+        self.0 & 0x7fff == 0x7c00
+    }
+}
+
+impl From<half> for f32 {
+    #[inline]
+    fn from(value: half) -> Self {
+        value.to_f32()
+    }
+}
+
+impl From<half> for f64 {
+    #[inline]
+    fn from(value: half) -> Self {
+        value.to_f64()
+    }
+}
+
+impl fmt::Debug for half {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_f32(), f)
+    }
+}
+
+impl fmt::Display for half {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_f32(), f)
+    }
+}
+
+impl PartialEq for half {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.to_f32() == other.to_f32()
+    }
+}
+
+impl PartialOrd for half {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.to_f32().partial_cmp(&other.to_f32())
+    }
+}
+
+impl Neg for half {
+    type Output = half;
+
+    #[inline]
+    fn neg(self) -> half {
+        half(self.0 ^ 0x8000)
+    }
+}
+
+forward_ref_unop! { impl Neg, neg for half }
+
+macro_rules! binop_impl {
+    (impl $imp:ident, $method:ident, $imp_assign:ident, $method_assign:ident) => {
+        impl $imp for half {
+            type Output = half;
+
+            #[inline]
+            fn $method(self, other: half) -> half {
+                half::from_f32($imp::$method(self.to_f32(), other.to_f32()))
+            }
+        }
+
+        forward_ref_binop! { impl $imp, $method for half, half }
+
+        impl $imp_assign for half {
+            #[inline]
+            fn $method_assign(&mut self, other: half) {
+                *self = $imp::$method(*self, other);
+            }
+        }
+
+        forward_ref_op_assign! { impl $imp_assign, $method_assign for half, half }
+    }
+}
+
+binop_impl! { impl Add, add, AddAssign, add_assign }
+binop_impl! { impl Sub, sub, SubAssign, sub_assign }
+binop_impl! { impl Mul, mul, MulAssign, mul_assign }
+binop_impl! { impl Div, div, DivAssign, div_assign }
+binop_impl! { impl Rem, rem, RemAssign, rem_assign }
+
+impl Scalar for half {}
+
+impl Number for half {
+    const ZERO: Self = half::ZERO;
+    const ONE: Self = half::ONE;
+}
+
+impl SignedNumber for half {}
+
+impl Bounded for half {
+    const MIN: Self = half::MIN;
+    const MAX: Self = half::MAX;
+}
+
+macro_rules! float_impl {
+    ($($method:ident($($arg:ident),*);)*) => {
+        impl Float for half {
+            const EPSILON: Self = half::EPSILON;
+            const NAN: Self = half::NAN;
+            const INFINITY: Self = half::INFINITY;
+            const NEG_INFINITY: Self = half::NEG_INFINITY;
+
+            #[inline]
+            fn from_f64(value: f64) -> Self {
+                half::from_f64(value)
+            }
+
+            $(
+                #[inline]
+                fn $method(self, $($arg: Self),*) -> Self {
+                    half::from_f32(Float::$method(self.to_f32(), $($arg.to_f32()),*))
+                }
+            )*
+        }
+    }
+}
+
+float_impl! {
+    abs();
+    floor();
+    ceil();
+    round();
+    trunc();
+    sqrt();
+    exp();
+    exp2();
+    ln();
+    log2();
+    powf(n);
+    sin();
+    cos();
+    tan();
+    asin();
+    acos();
+    atan();
+    atan2(other);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::half;
+
+    #[test]
+    fn from_f64_rounds_once() {
+        // 1 + 2^-11 + 2^-40 is just above the midpoint of 1 and 1 + 2^-10, but
+        // rounds to the midpoint in f32 and from there to even.
+        let value = 1.0 + 2f64.powi(-11) + 2f64.powi(-40);
+        assert_eq!(half::from_f64(value).to_bits(), 0x3c01);
+        assert_eq!(half::from_f32(value as f32).to_bits(), 0x3c00);
+        assert_eq!(half::from_f64(-value).to_bits(), 0xbc01);
+        // The same just above the midpoint of the two smallest subnormals.
+        assert_eq!(half::from_f64(1.5 * 2f64.powi(-24) + 2f64.powi(-60)).to_bits(), 0x0002);
+    }
+
+    #[test]
+    fn from_f64_rounds_to_nearest_even() {
+        // The midpoint above MAX is 65520, which overflows to infinity.
+        for bits in 0..0x7bffu16 {
+            let (low, high) = (half::from_bits(bits), half::from_bits(bits + 1));
+            let (a, b) = (low.to_f64(), high.to_f64());
+            assert_eq!(half::from_f64(a).to_bits(), bits);
+            assert_eq!(half::from_f64(-a).to_bits(), bits | 0x8000);
+
+            let midpoint = (a + b) / 2.0;
+            let even = if bits & 1 == 0 { bits } else { bits + 1 };
+            assert_eq!(half::from_f64(midpoint).to_bits(), even);
+            assert_eq!(half::from_f64(f64::from_bits(midpoint.to_bits() - 1)).to_bits(), bits);
+            assert_eq!(half::from_f64(f64::from_bits(midpoint.to_bits() + 1)).to_bits(), bits + 1);
+        }
+    }
+
+    #[test]
+    fn from_f64_special_values() {
+        assert_eq!(half::from_f64(f64::INFINITY).to_bits(), 0x7c00);
+        assert_eq!(half::from_f64(f64::NEG_INFINITY).to_bits(), 0xfc00);
+        assert!(half::from_f64(f64::NAN).is_nan());
+        assert!(half::from_f64(f64::from_bits(0x7ff0_0000_0000_0001)).is_nan());
+        assert_eq!(half::from_f64(65520.0).to_bits(), 0x7c00);
+        assert_eq!(half::from_f64(65519.99).to_bits(), 0x7bff);
+        assert_eq!(half::from_f64(2f64.powi(-25)).to_bits(), 0x0000);
+        assert_eq!(half::from_f64(2f64.powi(-25) + 2f64.powi(-70)).to_bits(), 0x0001);
+        assert_eq!(half::from_f64(f64::MIN_POSITIVE).to_bits(), 0x0000);
+        assert_eq!(half::from_f64(-0.0).to_bits(), 0x8000);
+        for bits in (0..u32::MAX).step_by(4099) {
+            let value = f32::from_bits(bits);
+            let (from_f32, from_f64) = (half::from_f32(value), half::from_f64(value as f64));
+            assert!(from_f32.to_bits() == from_f64.to_bits() || from_f32.is_nan() && from_f64.is_nan(), "{bits:#x}");
+        }
+    }
+}
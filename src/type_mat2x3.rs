@@ -0,0 +1,6 @@
+use crate::Matrix;
+
+#[allow(non_camel_case_types)]
+pub type mat2x3<T = f32> = Matrix<T, 2, 3>;
+#[allow(non_camel_case_types)]
+pub type dmat2x3 = mat2x3<f64>;
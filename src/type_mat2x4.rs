@@ -0,0 +1,6 @@
+use crate::Matrix;
+
+#[allow(non_camel_case_types)]
+pub type mat2x4<T = f32> = Matrix<T, 2, 4>;
+#[allow(non_camel_case_types)]
+pub type dmat2x4 = mat2x4<f64>;
@@ -0,0 +1,6 @@
+use crate::Matrix;
+
+#[allow(non_camel_case_types)]
+pub type mat3x2<T = f32> = Matrix<T, 3, 2>;
+#[allow(non_camel_case_types)]
+pub type dmat3x2 = mat3x2<f64>;
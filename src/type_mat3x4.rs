@@ -0,0 +1,6 @@
+use crate::Matrix;
+
+#[allow(non_camel_case_types)]
+pub type mat3x4<T = f32> = Matrix<T, 3, 4>;
+#[allow(non_camel_case_types)]
+pub type dmat3x4 = mat3x4<f64>;
@@ -0,0 +1,6 @@
+use crate::Matrix;
+
+#[allow(non_camel_case_types)]
+pub type mat4x2<T = f32> = Matrix<T, 4, 2>;
+#[allow(non_camel_case_types)]
+pub type dmat4x2 = mat4x2<f64>;
@@ -0,0 +1,6 @@
+use crate::Matrix;
+
+#[allow(non_camel_case_types)]
+pub type mat4x3<T = f32> = Matrix<T, 4, 3>;
+#[allow(non_camel_case_types)]
+pub type dmat4x3 = mat4x3<f64>;
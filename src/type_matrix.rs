@@ -0,0 +1,338 @@
+use core::{array, fmt, ops::{Add, AddAssign, Deref, DerefMut, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign}, slice::SliceIndex};
+
+use crate::{func_geometric::tree_sum, half, Number, SignedNumber, Vector};
+
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub struct Matrix<T, const C: usize, const R: usize> {
+    pub(crate) value: [Vector<T, R>; C],
+}
+
+// Prints the sizes with an alias under the alias name, like the separate
+// matrix structs did.
+impl<T: fmt::Debug, const C: usize, const R: usize> fmt::Debug for Matrix<T, C, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const NAMES: [[&str; 3]; 3] = [
+            ["mat2", "mat2x3", "mat2x4"],
+            ["mat3x2", "mat3", "mat3x4"],
+            ["mat4x2", "mat4x3", "mat4"],
+        ];
+        let name = if (2..=4).contains(&C) && (2..=4).contains(&R) { NAMES[C - 2][R - 2] } else { "Matrix" };
+        f.debug_struct(name).field("value", &self.value).finish()
+    }
+}
+
+impl<T, const C: usize, const R: usize> Matrix<T, C, R> {
+    #[inline]
+    pub const fn from_cols(value: [Vector<T, R>; C]) -> Self {
+        Self { value }
+    }
+    #[inline]
+    pub const fn as_ptr(&self) -> *const Vector<T, R> {
+        self.value.as_ptr()
+    }
+    #[inline]
+    pub const fn as_mut_ptr(&mut self) -> *mut Vector<T, R> {
+        self.value.as_mut_ptr()
+    }
+    #[inline]
+    pub const fn len(&self) -> usize {
+        C
+    }
+    #[inline]
+    pub const fn as_slice(&self) -> &[Vector<T, R>] {
+        &self.value
+    }
+    #[inline]
+    pub const fn as_mut_slice(&mut self) -> &mut [Vector<T, R>] {
+        &mut self.value
+    }
+}
+
+impl<T, const R: usize> Matrix<T, 2, R> {
+    #[inline]
+    pub const fn new(v0: Vector<T, R>, v1: Vector<T, R>) -> Self {
+        Self { value: [v0, v1] }
+    }
+}
+
+impl<T, const R: usize> Matrix<T, 3, R> {
+    #[inline]
+    pub const fn new(v0: Vector<T, R>, v1: Vector<T, R>, v2: Vector<T, R>) -> Self {
+        Self { value: [v0, v1, v2] }
+    }
+}
+
+impl<T, const R: usize> Matrix<T, 4, R> {
+    #[inline]
+    pub const fn new(v0: Vector<T, R>, v1: Vector<T, R>, v2: Vector<T, R>, v3: Vector<T, R>) -> Self {
+        Self { value: [v0, v1, v2, v3] }
+    }
+}
+
+impl<T, const C: usize, const R: usize> Deref for Matrix<T, C, R> {
+    type Target = [Vector<T, R>];
+
+    #[inline]
+    fn deref(&self) -> &[Vector<T, R>] {
+        self.as_slice()
+    }
+}
+
+impl<T, const C: usize, const R: usize> DerefMut for Matrix<T, C, R> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [Vector<T, R>] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, I: SliceIndex<[Vector<T, R>]>, const C: usize, const R: usize> Index<I> for Matrix<T, C, R> {
+    type Output = I::Output;
+
+    #[inline]
+    fn index(&self, index: I) -> &Self::Output {
+        Index::index(&**self, index)
+    }
+}
+
+impl<T, I: SliceIndex<[Vector<T, R>]>, const C: usize, const R: usize> IndexMut<I> for Matrix<T, C, R> {
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        IndexMut::index_mut(&mut **self, index)
+    }
+}
+
+impl<T: Number, const C: usize, const R: usize> Matrix<T, C, R> {
+    #[inline]
+    pub const fn identity() -> Self {
+        let mut value = [Vector::zero(); C];
+        let mut i = 0;
+        while i < C && i < R {
+            value[i].0[i] = T::ONE;
+            i += 1;
+        }
+        Self { value }
+    }
+}
+
+impl<T: Number, const C: usize, const R: usize> Default for Matrix<T, C, R> {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+macro_rules! binop_impl {
+    (impl[$($g:tt)*] $imp:ident, $method:ident) => {
+        impl<$($g)*, const C: usize, const R: usize> $imp<T> for Matrix<T, C, R> {
+            type Output = Matrix<T, C, R>;
+
+            #[inline]
+            fn $method(self, other: T) -> Matrix<T, C, R> {
+                Matrix { value: self.value.map(|v| $imp::$method(v, other)) }
+            }
+        }
+
+        forward_ref_binop! { impl[$($g)*, const C: usize, const R: usize] $imp, $method for Matrix<T, C, R>, T }
+    }
+}
+
+macro_rules! componentwise_binop_impl {
+    (impl[$($g:tt)*] $imp:ident, $method:ident) => {
+        impl<$($g)*, const C: usize, const R: usize> $imp<Matrix<T, C, R>> for Matrix<T, C, R> {
+            type Output = Matrix<T, C, R>;
+
+            #[inline]
+            fn $method(self, other: Matrix<T, C, R>) -> Matrix<T, C, R> {
+                Matrix { value: array::from_fn(|i| $imp::$method(self.value[i], other.value[i])) }
+            }
+        }
+
+        forward_ref_binop! { impl[$($g)*, const C: usize, const R: usize] $imp, $method for Matrix<T, C, R>, Matrix<T, C, R> }
+    }
+}
+
+macro_rules! scalar_binop_impl {
+    (impl $imp:ident, $method:ident for $t:ty) => {
+        impl<const C: usize, const R: usize> $imp<Matrix<$t, C, R>> for $t {
+            type Output = Matrix<$t, C, R>;
+
+            #[inline]
+            fn $method(self, other: Matrix<$t, C, R>) -> Matrix<$t, C, R> {
+                Matrix { value: other.value.map(|v| $imp::$method(self, v)) }
+            }
+        }
+
+        forward_ref_binop! { impl[const C: usize, const R: usize] $imp, $method for $t, Matrix<$t, C, R> }
+    }
+}
+
+macro_rules! op_assign_impl {
+    (impl[$($g:tt)*] $imp:ident, $method:ident) => {
+        impl<$($g)*, const C: usize, const R: usize> $imp<T> for Matrix<T, C, R> {
+            #[inline]
+            fn $method(&mut self, other: T) {
+                for v in &mut self.value {
+                    $imp::$method(v, other);
+                }
+            }
+        }
+
+        forward_ref_op_assign! { impl[$($g)*, const C: usize, const R: usize] $imp, $method for Matrix<T, C, R>, T }
+    }
+}
+
+macro_rules! componentwise_op_assign_impl {
+    (impl[$($g:tt)*] $imp:ident, $method:ident) => {
+        impl<$($g)*, const C: usize, const R: usize> $imp<Matrix<T, C, R>> for Matrix<T, C, R> {
+            #[inline]
+            fn $method(&mut self, other: Matrix<T, C, R>) {
+                for (v, w) in self.value.iter_mut().zip(other.value) {
+                    $imp::$method(v, w);
+                }
+            }
+        }
+
+        forward_ref_op_assign! { impl[$($g)*, const C: usize, const R: usize] $imp, $method for Matrix<T, C, R>, Matrix<T, C, R> }
+    }
+}
+
+binop_impl! { impl[T: Number] Add, add }
+binop_impl! { impl[T: Number] Sub, sub }
+binop_impl! { impl[T: Number] Mul, mul }
+binop_impl! { impl[T: Number] Div, div }
+componentwise_binop_impl! { impl[T: Number] Add, add }
+componentwise_binop_impl! { impl[T: Number] Sub, sub }
+
+op_assign_impl! { impl[T: Number] AddAssign, add_assign }
+op_assign_impl! { impl[T: Number] SubAssign, sub_assign }
+op_assign_impl! { impl[T: Number] MulAssign, mul_assign }
+op_assign_impl! { impl[T: Number] DivAssign, div_assign }
+componentwise_op_assign_impl! { impl[T: Number] AddAssign, add_assign }
+componentwise_op_assign_impl! { impl[T: Number] SubAssign, sub_assign }
+
+macro_rules! scalar_arith_impl {
+    ($($t:ty)*) => ($(
+        scalar_binop_impl! { impl Add, add for $t }
+        scalar_binop_impl! { impl Sub, sub for $t }
+        scalar_binop_impl! { impl Mul, mul for $t }
+        scalar_binop_impl! { impl Div, div for $t }
+    )*)
+}
+
+scalar_arith_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 half }
+
+impl<T: SignedNumber, const C: usize, const R: usize> Neg for Matrix<T, C, R> {
+    type Output = Matrix<T, C, R>;
+
+    #[inline]
+    fn neg(self) -> Matrix<T, C, R> {
+        Matrix { value: self.value.map(Neg::neg) }
+    }
+}
+
+forward_ref_unop! { impl[T: SignedNumber, const C: usize, const R: usize] Neg, neg for Matrix<T, C, R> }
+
+impl<T: Number, const C: usize, const R: usize> Mul<Vector<T, C>> for Matrix<T, C, R> {
+    type Output = Vector<T, R>;
+
+    #[inline]
+    fn mul(self, other: Vector<T, C>) -> Vector<T, R> {
+        tree_sum(&array::from_fn::<Vector<T, R>, C, _>(|i| self.value[i] * other.0[i]), Vector::ZERO)
+    }
+}
+
+forward_ref_binop! { impl[T: Number, const C: usize, const R: usize] Mul, mul for Matrix<T, C, R>, Vector<T, C> }
+
+impl<T: Number, const C: usize, const R: usize> Mul<Matrix<T, C, R>> for Vector<T, R> {
+    type Output = Vector<T, C>;
+
+    #[inline]
+    fn mul(self, other: Matrix<T, C, R>) -> Vector<T, C> {
+        Vector(other.value.map(|v| self.dot(v)))
+    }
+}
+
+forward_ref_binop! { impl[T: Number, const C: usize, const R: usize] Mul, mul for Vector<T, R>, Matrix<T, C, R> }
+
+impl<T: Number, const K: usize, const C: usize, const R: usize> Mul<Matrix<T, K, C>> for Matrix<T, C, R> {
+    type Output = Matrix<T, K, R>;
+
+    #[inline]
+    fn mul(self, other: Matrix<T, K, C>) -> Matrix<T, K, R> {
+        // Accumulated column by column like GLM, which sums differently from
+        // the matrix vector product.
+        Matrix {
+            value: other.value.map(|v| {
+                let mut result = self.value[0] * v.0[0];
+                for i in 1..C {
+                    result += self.value[i] * v.0[i];
+                }
+                result
+            }),
+        }
+    }
+}
+
+forward_ref_binop! { impl[T: Number, const K: usize, const C: usize, const R: usize] Mul, mul for Matrix<T, C, R>, Matrix<T, K, C> }
+
+impl<T: Number, const C: usize, const R: usize> MulAssign<Matrix<T, C, C>> for Matrix<T, C, R> {
+    #[inline]
+    fn mul_assign(&mut self, other: Matrix<T, C, C>) {
+        *self = Mul::mul(*self, other)
+    }
+}
+
+forward_ref_op_assign! { impl[T: Number, const C: usize, const R: usize] MulAssign, mul_assign for Matrix<T, C, R>, Matrix<T, C, C> }
+
+// Division by a matrix multiplies by its inverse, which only exists for the
+// square sizes that have an `inverse` implementation.
+macro_rules! div_impl {
+    ($($n:literal)*) => ($(
+        impl<T: SignedNumber> DivAssign<Matrix<T, $n, $n>> for Matrix<T, $n, $n> {
+            #[inline]
+            fn div_assign(&mut self, other: Matrix<T, $n, $n>) {
+                MulAssign::mul_assign(self, other.inverse())
+            }
+        }
+
+        forward_ref_op_assign! { impl[T: SignedNumber] DivAssign, div_assign for Matrix<T, $n, $n>, Matrix<T, $n, $n> }
+
+        impl<T: SignedNumber> Div<Vector<T, $n>> for Matrix<T, $n, $n> {
+            type Output = Vector<T, $n>;
+
+            #[inline]
+            fn div(self, other: Vector<T, $n>) -> Vector<T, $n> {
+                Mul::mul(self.inverse(), other)
+            }
+        }
+
+        forward_ref_binop! { impl[T: SignedNumber] Div, div for Matrix<T, $n, $n>, Vector<T, $n> }
+
+        impl<T: SignedNumber> Div<Matrix<T, $n, $n>> for Vector<T, $n> {
+            type Output = Vector<T, $n>;
+
+            #[inline]
+            fn div(self, other: Matrix<T, $n, $n>) -> Vector<T, $n> {
+                Mul::mul(self, other.inverse())
+            }
+        }
+
+        forward_ref_binop! { impl[T: SignedNumber] Div, div for Vector<T, $n>, Matrix<T, $n, $n> }
+
+        impl<T: SignedNumber> Div<Matrix<T, $n, $n>> for Matrix<T, $n, $n> {
+            type Output = Matrix<T, $n, $n>;
+
+            #[inline]
+            fn div(self, other: Matrix<T, $n, $n>) -> Matrix<T, $n, $n> {
+                let mut self_copy = self;
+                DivAssign::div_assign(&mut self_copy, other);
+                self_copy
+            }
+        }
+
+        forward_ref_binop! { impl[T: SignedNumber] Div, div for Matrix<T, $n, $n>, Matrix<T, $n, $n> }
+    )*)
+}
+
+div_impl! { 2 3 4 }
@@ -0,0 +1,471 @@
+use crate::{mat3, mat4, vec3, vec4, Float};
+
+#[inline]
+fn min<T: Float>(a: vec3<T>, b: vec3<T>) -> vec3<T> {
+    vec3::new(
+        if b.x < a.x { b.x } else { a.x },
+        if b.y < a.y { b.y } else { a.y },
+        if b.z < a.z { b.z } else { a.z })
+}
+
+#[inline]
+fn max<T: Float>(a: vec3<T>, b: vec3<T>) -> vec3<T> {
+    vec3::new(
+        if b.x > a.x { b.x } else { a.x },
+        if b.y > a.y { b.y } else { a.y },
+        if b.z > a.z { b.z } else { a.z })
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub struct Ray<T> {
+    pub origin: vec3<T>,
+    pub direction: vec3<T>,
+}
+
+impl<T> Ray<T> {
+    #[inline]
+    pub const fn new(origin: vec3<T>, direction: vec3<T>) -> Self {
+        Self { origin, direction }
+    }
+}
+
+impl<T: Float> Ray<T> {
+    #[inline]
+    pub fn at(&self, t: T) -> vec3<T> {
+        self.origin + self.direction * t
+    }
+}
+
+// The points p with dot(normal, p) + distance == 0, so that the plane is
+// the vec4 (normal, distance) of the plane equation.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub struct Plane<T> {
+    pub normal: vec3<T>,
+    pub distance: T,
+}
+
+impl<T> Plane<T> {
+    #[inline]
+    pub const fn new(normal: vec3<T>, distance: T) -> Self {
+        Self { normal, distance }
+    }
+}
+
+impl<T: Float> Plane<T> {
+    #[inline]
+    pub fn from_normal_and_point(normal: vec3<T>, point: vec3<T>) -> Self {
+        Self::new(normal, -normal.dot(point))
+    }
+
+    // The normal faces the side from which a, b and c are counterclockwise.
+    // Collinear points span no plane, and give a NaN normal and distance.
+    #[inline]
+    pub fn from_points(a: vec3<T>, b: vec3<T>, c: vec3<T>) -> Self {
+        Self::from_normal_and_point((b - a).cross(c - a).normalize(), a)
+    }
+
+    // Positive on the side the normal faces, and in units of the normal's
+    // length, so a true distance only once normalized.
+    #[inline]
+    pub fn signed_distance(&self, point: vec3<T>) -> T {
+        self.normal.dot(point) + self.distance
+    }
+
+    #[inline]
+    pub fn normalize(&self) -> Self {
+        let one_over_length = T::ONE / self.normal.length();
+        Self::new(self.normal * one_over_length, self.distance * one_over_length)
+    }
+}
+
+impl<T: Float> From<vec4<T>> for Plane<T> {
+    #[inline]
+    fn from(v: vec4<T>) -> Self {
+        Self::new(vec3::new(v.x, v.y, v.z), v.w)
+    }
+}
+
+impl<T: Float> From<Plane<T>> for vec4<T> {
+    #[inline]
+    fn from(plane: Plane<T>) -> Self {
+        vec4::new(plane.normal.x, plane.normal.y, plane.normal.z, plane.distance)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub struct Sphere<T> {
+    pub center: vec3<T>,
+    pub radius: T,
+}
+
+impl<T> Sphere<T> {
+    #[inline]
+    pub const fn new(center: vec3<T>, radius: T) -> Self {
+        Self { center, radius }
+    }
+}
+
+impl<T: Float> Sphere<T> {
+    // Ritter's bounding sphere: a few percent larger than the smallest one,
+    // in two passes over the points. None when there are no points.
+    pub fn from_points(points: &[vec3<T>]) -> Option<Self> {
+        let first = *points.first()?;
+        let farthest = |from: vec3<T>| {
+            points.iter().copied().fold(from, |best, p| {
+                if (p - from).dot(p - from) > (best - from).dot(best - from) { p } else { best }
+            })
+        };
+        let a = farthest(first);
+        let b = farthest(a);
+        let half = T::from_f64(0.5);
+        let mut sphere = Self::new((a + b) * half, a.distance(b) * half);
+
+        for &p in points {
+            let distance = p.distance(sphere.center);
+            if distance > sphere.radius {
+                let radius = (sphere.radius + distance) * half;
+                sphere.center += (p - sphere.center) * ((radius - sphere.radius) / distance);
+                sphere.radius = radius;
+            }
+        }
+        Some(sphere)
+    }
+
+    #[inline]
+    pub fn contains(&self, point: vec3<T>) -> bool {
+        (point - self.center).dot(point - self.center) <= self.radius * self.radius
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub struct Aabb<T> {
+    pub min: vec3<T>,
+    pub max: vec3<T>,
+}
+
+impl<T> Aabb<T> {
+    #[inline]
+    pub const fn new(min: vec3<T>, max: vec3<T>) -> Self {
+        Self { min, max }
+    }
+}
+
+impl<T: Float> Aabb<T> {
+    // Contains nothing, and is the identity for union and extend.
+    pub const EMPTY: Self = Self::new(vec3::splat(T::INFINITY), vec3::splat(T::NEG_INFINITY));
+
+    #[inline]
+    pub const fn empty() -> Self {
+        Self::EMPTY
+    }
+
+    #[inline]
+    pub fn from_points(points: &[vec3<T>]) -> Self {
+        points.iter().fold(Self::EMPTY, |aabb, &p| aabb.extend(p))
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.min.x > self.max.x || self.min.y > self.max.y || self.min.z > self.max.z
+    }
+
+    #[inline]
+    pub fn center(&self) -> vec3<T> {
+        (self.min + self.max) * T::from_f64(0.5)
+    }
+
+    #[inline]
+    pub fn size(&self) -> vec3<T> {
+        self.max - self.min
+    }
+
+    // Half the size.
+    #[inline]
+    pub fn extents(&self) -> vec3<T> {
+        self.size() * T::from_f64(0.5)
+    }
+
+    #[inline]
+    pub fn extend(&self, point: vec3<T>) -> Self {
+        Self::new(min(self.min, point), max(self.max, point))
+    }
+
+    #[inline]
+    pub fn union(&self, other: &Self) -> Self {
+        Self::new(min(self.min, other.min), max(self.max, other.max))
+    }
+
+    // Boundaries included.
+    #[inline]
+    pub fn contains(&self, point: vec3<T>) -> bool {
+        point.x >= self.min.x && point.x <= self.max.x
+            && point.y >= self.min.y && point.y <= self.max.y
+            && point.z >= self.min.z && point.z <= self.max.z
+    }
+
+    #[inline]
+    pub fn corners(&self) -> [vec3<T>; 8] {
+        let (a, b) = (self.min, self.max);
+        [
+            vec3::new(a.x, a.y, a.z), vec3::new(b.x, a.y, a.z), vec3::new(a.x, b.y, a.z), vec3::new(b.x, b.y, a.z),
+            vec3::new(a.x, a.y, b.z), vec3::new(b.x, a.y, b.z), vec3::new(a.x, b.y, b.z), vec3::new(b.x, b.y, b.z),
+        ]
+    }
+
+    // The box around the transformed box, after Arvo, for an affine matrix.
+    #[inline]
+    pub fn transform(&self, m: &mat4<T>) -> Self {
+        if self.is_empty() {
+            return *self;
+        }
+        let (center, extents) = (self.center(), self.extents());
+        let mut new_center = vec3::new(m.value[3].x, m.value[3].y, m.value[3].z);
+        let mut new_extents = vec3::splat(T::ZERO);
+        for (column, (&c, &e)) in m.value[..3].iter().zip(center.0.iter().zip(extents.0.iter())) {
+            let column = vec3::new(column.x, column.y, column.z);
+            new_center += column * c;
+            new_extents += column.map(|x| x.abs()) * e;
+        }
+        Self::new(new_center - new_extents, new_center + new_extents)
+    }
+}
+
+// A box with the given half extents along the columns of the orientation,
+// which must be a rotation. Debug builds check this on use.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub struct Obb<T> {
+    pub center: vec3<T>,
+    pub half_extents: vec3<T>,
+    pub orientation: mat3<T>,
+}
+
+impl<T> Obb<T> {
+    #[inline]
+    pub const fn new(center: vec3<T>, half_extents: vec3<T>, orientation: mat3<T>) -> Self {
+        Self { center, half_extents, orientation }
+    }
+}
+
+impl<T: Float> Obb<T> {
+    // The columns are unit length and perpendicular to within a loose
+    // tolerance, enough to catch a scaled or sheared orientation.
+    #[inline]
+    fn debug_assert_orthonormal(&self) {
+        debug_assert!(
+            {
+                let product = self.orientation.transpose() * self.orientation;
+                (0..3).all(|i| (0..3).all(|j| {
+                    let expected = if i == j { T::ONE } else { T::ZERO };
+                    (product.value[i].0[j] - expected).abs() <= T::from_f64(1e-4)
+                }))
+            },
+            "Obb orientation must be a rotation");
+    }
+
+    // Boundaries included.
+    #[inline]
+    pub fn contains(&self, point: vec3<T>) -> bool {
+        self.debug_assert_orthonormal();
+        let local = self.orientation.transpose() * (point - self.center);
+        local.x.abs() <= self.half_extents.x && local.y.abs() <= self.half_extents.y && local.z.abs() <= self.half_extents.z
+    }
+
+    #[inline]
+    pub fn corners(&self) -> [vec3<T>; 8] {
+        self.debug_assert_orthonormal();
+        Aabb::new(-self.half_extents, self.half_extents).corners().map(|p| self.center + self.orientation * p)
+    }
+
+    #[inline]
+    pub fn aabb(&self) -> Aabb<T> {
+        self.debug_assert_orthonormal();
+        let extents = vec3::new(
+            self.orientation.value[0].x.abs() * self.half_extents.x + self.orientation.value[1].x.abs() * self.half_extents.y + self.orientation.value[2].x.abs() * self.half_extents.z,
+            self.orientation.value[0].y.abs() * self.half_extents.x + self.orientation.value[1].y.abs() * self.half_extents.y + self.orientation.value[2].y.abs() * self.half_extents.z,
+            self.orientation.value[0].z.abs() * self.half_extents.x + self.orientation.value[1].z.abs() * self.half_extents.y + self.orientation.value[2].z.abs() * self.half_extents.z);
+        Aabb::new(self.center - extents, self.center + extents)
+    }
+}
+
+impl<T: Float> From<Aabb<T>> for Obb<T> {
+    #[inline]
+    fn from(aabb: Aabb<T>) -> Self {
+        Self::new(aabb.center(), aabb.extents(), mat3::identity())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub struct Triangle<T> {
+    pub a: vec3<T>,
+    pub b: vec3<T>,
+    pub c: vec3<T>,
+}
+
+impl<T> Triangle<T> {
+    #[inline]
+    pub const fn new(a: vec3<T>, b: vec3<T>, c: vec3<T>) -> Self {
+        Self { a, b, c }
+    }
+}
+
+impl<T: Float> Triangle<T> {
+    #[inline]
+    pub fn area(&self) -> T {
+        (self.b - self.a).cross(self.c - self.a).length() * T::from_f64(0.5)
+    }
+
+    // Unit length, facing the side from which a, b and c are
+    // counterclockwise. NaN for a degenerate triangle, whose zero area
+    // leaves no direction to normalize.
+    #[inline]
+    pub fn normal(&self) -> vec3<T> {
+        (self.b - self.a).cross(self.c - self.a).normalize()
+    }
+
+    // The weights of a, b and c for the projection of the point onto the
+    // plane of the triangle. They sum to one, and are all in [0, 1] inside.
+    // Not finite for a degenerate triangle, where the weights are not
+    // unique.
+    #[inline]
+    pub fn barycentric(&self, point: vec3<T>) -> vec3<T> {
+        let (e0, e1, p) = (self.b - self.a, self.c - self.a, point - self.a);
+        let (d00, d01, d11) = (e0.dot(e0), e0.dot(e1), e1.dot(e1));
+        let (d20, d21) = (p.dot(e0), p.dot(e1));
+        let one_over_denominator = T::ONE / (d00 * d11 - d01 * d01);
+        let v = (d11 * d20 - d01 * d21) * one_over_denominator;
+        let w = (d00 * d21 - d01 * d20) * one_over_denominator;
+        vec3::new(T::ONE - v - w, v, w)
+    }
+}
+
+// Planes in the order left, right, bottom, top, near, far, with the
+// normals facing inwards.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub struct Frustum<T> {
+    pub planes: [Plane<T>; 6],
+}
+
+impl<T> Frustum<T> {
+    #[inline]
+    pub const fn new(planes: [Plane<T>; 6]) -> Self {
+        Self { planes }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{mat3, mat4, vec3, vec4, Aabb, Obb, Plane, Ray, Sphere, Triangle};
+
+    fn close(a: vec3, b: vec3) -> bool {
+        (a - b).0.iter().all(|x| x.abs() <= 1e-5)
+    }
+
+    #[test]
+    fn ray() {
+        let ray = Ray::new(vec3::new(1.0, 2.0, 3.0), vec3::new(0.0, -1.0, 2.0));
+        assert_eq!(ray.at(0.0), ray.origin);
+        assert_eq!(ray.at(1.5), vec3::new(1.0, 0.5, 6.0));
+    }
+
+    #[test]
+    fn plane() {
+        let plane = Plane::from_points(vec3::new(0.0, 0.0, 2.0), vec3::new(1.0, 0.0, 2.0), vec3::new(0.0, 1.0, 2.0));
+        assert_eq!(plane, Plane::new(vec3::new(0.0, 0.0, 1.0), -2.0));
+        assert_eq!(plane.signed_distance(vec3::new(5.0, -3.0, 3.5)), 1.5);
+        assert_eq!(plane.signed_distance(vec3::new(0.0, 0.0, 0.0)), -2.0);
+
+        let scaled = Plane::from(vec4::new(0.0, 3.0, 4.0, -10.0));
+        let normalized = scaled.normalize();
+        assert!(close(normalized.normal, vec3::new(0.0, 0.6, 0.8)) && normalized.distance == -2.0, "{normalized:?}");
+        assert_eq!(vec4::from(scaled), vec4::new(0.0, 3.0, 4.0, -10.0));
+
+        let degenerate = Plane::<f32>::from_points(vec3::splat(0.0), vec3::splat(1.0), vec3::splat(2.0));
+        assert!(degenerate.normal.0.iter().all(|x| x.is_nan()) && degenerate.distance.is_nan());
+    }
+
+    #[test]
+    fn sphere() {
+        assert_eq!(Sphere::<f32>::from_points(&[]), None);
+        let points = [
+            vec3::new(1.0, 0.0, 0.0), vec3::new(-1.0, 0.0, 0.0), vec3::new(0.0, 1.0, 0.0),
+            vec3::new(0.0, -1.0, 0.0), vec3::new(0.0, 0.0, 1.0), vec3::new(0.3, 0.4, -0.5),
+        ];
+        let sphere = Sphere::from_points(&points).unwrap();
+        assert!(points.iter().all(|&p| sphere.contains(p)), "{sphere:?}");
+        assert!(sphere.radius >= 1.0 && sphere.radius <= 1.1, "{sphere:?}");
+        assert_eq!(Sphere::from_points(&[vec3::splat(2.0)]), Some(Sphere::new(vec3::splat(2.0), 0.0)));
+        assert!(!sphere.contains(vec3::new(1.2, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn aabb() {
+        assert!(Aabb::<f32>::EMPTY.is_empty());
+        assert_eq!(Aabb::<f32>::from_points(&[]), Aabb::EMPTY);
+        let aabb = Aabb::from_points(&[vec3::new(1.0, -2.0, 0.0), vec3::new(-1.0, 4.0, 2.0), vec3::new(0.0, 0.0, 1.0)]);
+        assert_eq!(aabb, Aabb::new(vec3::new(-1.0, -2.0, 0.0), vec3::new(1.0, 4.0, 2.0)));
+        assert_eq!(aabb.center(), vec3::new(0.0, 1.0, 1.0));
+        assert_eq!(aabb.extents(), vec3::new(1.0, 3.0, 1.0));
+        assert!(aabb.contains(aabb.min) && aabb.contains(aabb.max) && !aabb.contains(vec3::new(0.0, 4.5, 1.0)));
+        assert_eq!(aabb.union(&Aabb::EMPTY), aabb);
+        assert_eq!(aabb.union(&Aabb::new(vec3::splat(3.0), vec3::splat(5.0))).max, vec3::new(5.0, 5.0, 5.0));
+
+        // The transformed box bounds the transformed corners exactly.
+        let m = mat4::new(
+            vec4::new(0.0, 1.0, 0.0, 0.0),
+            vec4::new(-2.0, 0.0, 0.0, 0.0),
+            vec4::new(0.0, 0.0, 1.0, 0.0),
+            vec4::new(10.0, 0.0, -1.0, 1.0));
+        let corners = aabb.corners().map(|p| {
+            let p = m * vec4::new(p.x, p.y, p.z, 1.0);
+            vec3::new(p.x, p.y, p.z)
+        });
+        assert_eq!(aabb.transform(&m), Aabb::from_points(&corners));
+        assert!(Aabb::<f32>::EMPTY.transform(&m).is_empty());
+    }
+
+    #[test]
+    fn obb() {
+        // Rotated a quarter turn around z.
+        let orientation = mat3::new(vec3::new(0.0, 1.0, 0.0), vec3::new(-1.0, 0.0, 0.0), vec3::new(0.0, 0.0, 1.0));
+        let obb = Obb::new(vec3::new(1.0, 1.0, 1.0), vec3::new(2.0, 1.0, 0.5), orientation);
+        assert!(obb.contains(vec3::new(1.9, 2.9, 1.4)));
+        assert!(!obb.contains(vec3::new(2.9, 1.9, 1.4)));
+        assert!(obb.corners().iter().all(|&p| obb.contains(p)));
+        assert_eq!(obb.aabb(), Aabb::new(vec3::new(0.0, -1.0, 0.5), vec3::new(2.0, 3.0, 1.5)));
+
+        let aabb = Aabb::new(vec3::new(-1.0, 0.0, 2.0), vec3::new(3.0, 2.0, 3.0));
+        let obb = Obb::from(aabb);
+        assert_eq!(obb.aabb(), aabb);
+        assert_eq!(Aabb::from_points(&obb.corners()), aabb);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "Obb orientation must be a rotation")]
+    fn obb_rejects_a_scaled_orientation() {
+        Obb::new(vec3::splat(0.0), vec3::splat(1.0), mat3::identity() * 2.0).contains(vec3::splat(0.0));
+    }
+
+    #[test]
+    fn triangle() {
+        let triangle = Triangle::new(vec3::new(0.0, 0.0, 1.0), vec3::new(2.0, 0.0, 1.0), vec3::new(0.0, 2.0, 1.0));
+        assert_eq!(triangle.area(), 2.0);
+        assert_eq!(triangle.normal(), vec3::new(0.0, 0.0, 1.0));
+        assert_eq!(triangle.barycentric(triangle.a), vec3::new(1.0, 0.0, 0.0));
+        assert_eq!(triangle.barycentric(triangle.c), vec3::new(0.0, 0.0, 1.0));
+        assert!(close(triangle.barycentric(vec3::new(0.5, 0.5, 7.0)), vec3::new(0.5, 0.25, 0.25)));
+        assert!(close(triangle.barycentric(vec3::new(2.0, 2.0, 1.0)), vec3::new(-1.0, 1.0, 1.0)));
+
+        let degenerate = Triangle::<f32>::new(vec3::splat(0.0), vec3::splat(1.0), vec3::splat(2.0));
+        assert_eq!(degenerate.area(), 0.0);
+        assert!(degenerate.normal().0.iter().all(|x| x.is_nan()));
+        assert!(degenerate.barycentric(vec3::splat(0.5)).0.iter().all(|x| !x.is_finite()));
+    }
+}
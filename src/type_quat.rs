@@ -0,0 +1,169 @@
+use core::ops::{Mul, MulAssign};
+
+use crate::{mat3, mat4, vec3, vec4, Float, Number, SignedNumber};
+
+// Stored as x, y, z, w like GLM's default layout, with w the real part.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub struct Quaternion<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
+}
+
+#[allow(non_camel_case_types)]
+pub type quat<T = f32> = Quaternion<T>;
+#[allow(non_camel_case_types)]
+pub type dquat = quat<f64>;
+
+impl<T> quat<T> {
+    #[inline]
+    pub const fn new(x: T, y: T, z: T, w: T) -> Self {
+        Self { x, y, z, w }
+    }
+}
+
+impl<T: Number> quat<T> {
+    pub const IDENTITY: Self = Self::new(T::ZERO, T::ZERO, T::ZERO, T::ONE);
+
+    #[inline]
+    pub const fn identity() -> Self {
+        Self::IDENTITY
+    }
+
+    #[inline]
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+}
+
+impl<T: SignedNumber> quat<T> {
+    #[inline]
+    pub fn conjugate(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, self.w)
+    }
+}
+
+impl<T: Float> quat<T> {
+    #[inline]
+    pub fn length(self) -> T {
+        self.dot(self).sqrt()
+    }
+
+    #[inline]
+    pub fn normalize(self) -> Self {
+        let one_over_length = T::ONE / self.length();
+        Self::new(self.x * one_over_length, self.y * one_over_length, self.z * one_over_length, self.w * one_over_length)
+    }
+
+    #[inline]
+    pub fn inverse(self) -> Self {
+        let one_over_dot = T::ONE / self.dot(self);
+        let c = self.conjugate();
+        Self::new(c.x * one_over_dot, c.y * one_over_dot, c.z * one_over_dot, c.w * one_over_dot)
+    }
+}
+
+impl<T: Number> Mul for quat<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        Self::new(
+            self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            self.w * other.y + self.y * other.w + self.z * other.x - self.x * other.z,
+            self.w * other.z + self.z * other.w + self.x * other.y - self.y * other.x,
+            self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z)
+    }
+}
+
+forward_ref_binop! { impl[T: Number] Mul, mul for quat<T>, quat<T> }
+
+impl<T: Number> MulAssign for quat<T> {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+forward_ref_op_assign! { impl[T: Number] MulAssign, mul_assign for quat<T>, quat<T> }
+
+// Rotates the vector, assuming a unit quaternion.
+impl<T: Number> Mul<vec3<T>> for quat<T> {
+    type Output = vec3<T>;
+
+    #[inline]
+    fn mul(self, v: vec3<T>) -> vec3<T> {
+        let q = vec3::new(self.x, self.y, self.z);
+        let uv = q.cross(v);
+        let uuv = q.cross(uv);
+        v + (uv * self.w + uuv) * (T::ONE + T::ONE)
+    }
+}
+
+forward_ref_binop! { impl[T: Number] Mul, mul for quat<T>, vec3<T> }
+
+impl<T: Number> From<quat<T>> for mat3<T> {
+    #[inline]
+    fn from(q: quat<T>) -> Self {
+        let two = T::ONE + T::ONE;
+        let (qxx, qyy, qzz) = (q.x * q.x, q.y * q.y, q.z * q.z);
+        let (qxz, qxy, qyz) = (q.x * q.z, q.x * q.y, q.y * q.z);
+        let (qwx, qwy, qwz) = (q.w * q.x, q.w * q.y, q.w * q.z);
+
+        mat3::new(
+            vec3::new(T::ONE - two * (qyy + qzz), two * (qxy + qwz), two * (qxz - qwy)),
+            vec3::new(two * (qxy - qwz), T::ONE - two * (qxx + qzz), two * (qyz + qwx)),
+            vec3::new(two * (qxz + qwy), two * (qyz - qwx), T::ONE - two * (qxx + qyy)))
+    }
+}
+
+impl<T: Number> From<quat<T>> for mat4<T> {
+    #[inline]
+    fn from(q: quat<T>) -> Self {
+        let m = mat3::from(q);
+        mat4::new(
+            vec4::new(m.value[0].x, m.value[0].y, m.value[0].z, T::ZERO),
+            vec4::new(m.value[1].x, m.value[1].y, m.value[1].z, T::ZERO),
+            vec4::new(m.value[2].x, m.value[2].y, m.value[2].z, T::ZERO),
+            vec4::W)
+    }
+}
+
+// The rotation part of the matrix, which must be orthonormal.
+impl<T: Float> From<mat3<T>> for quat<T> {
+    #[inline]
+    fn from(m: mat3<T>) -> Self {
+        let m = m.value;
+        let four_x_squared_minus_1 = m[0].x - m[1].y - m[2].z;
+        let four_y_squared_minus_1 = m[1].y - m[0].x - m[2].z;
+        let four_z_squared_minus_1 = m[2].z - m[0].x - m[1].y;
+        let four_w_squared_minus_1 = m[0].x + m[1].y + m[2].z;
+
+        let mut biggest_index = 0;
+        let mut four_biggest_squared_minus_1 = four_w_squared_minus_1;
+        if four_x_squared_minus_1 > four_biggest_squared_minus_1 {
+            four_biggest_squared_minus_1 = four_x_squared_minus_1;
+            biggest_index = 1;
+        }
+        if four_y_squared_minus_1 > four_biggest_squared_minus_1 {
+            four_biggest_squared_minus_1 = four_y_squared_minus_1;
+            biggest_index = 2;
+        }
+        if four_z_squared_minus_1 > four_biggest_squared_minus_1 {
+            four_biggest_squared_minus_1 = four_z_squared_minus_1;
+            biggest_index = 3;
+        }
+
+        let half = T::ONE / (T::ONE + T::ONE);
+        let biggest_value = (four_biggest_squared_minus_1 + T::ONE).sqrt() * half;
+        let mult = half * half / biggest_value;
+        match biggest_index {
+            0 => Self::new((m[1].z - m[2].y) * mult, (m[2].x - m[0].z) * mult, (m[0].y - m[1].x) * mult, biggest_value),
+            1 => Self::new(biggest_value, (m[0].y + m[1].x) * mult, (m[2].x + m[0].z) * mult, (m[1].z - m[2].y) * mult),
+            2 => Self::new((m[0].y + m[1].x) * mult, biggest_value, (m[1].z + m[2].y) * mult, (m[2].x - m[0].z) * mult),
+            _ => Self::new((m[2].x + m[0].z) * mult, (m[1].z + m[2].y) * mult, biggest_value, (m[0].y - m[1].x) * mult),
+        }
+    }
+}
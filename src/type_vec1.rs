@@ -1,17 +1,17 @@
-use std::{ffi::{c_int, c_uint}, ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Deref, DerefMut, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign}, slice::{self, SliceIndex}};
+use core::{ffi::{c_int, c_uint}, ops::{Deref, DerefMut}};
+
+use crate::{half, swizzle::X, Number, Vector};
 
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
-#[repr(C)]
-pub struct vec1<T = f32> {
-    pub x: T,
-}
+pub type vec1<T = f32> = Vector<T, 1>;
 
 #[allow(non_camel_case_types)]
 pub type bvec1 = vec1<bool>;
 #[allow(non_camel_case_types)]
 pub type dvec1 = vec1<f64>;
 #[allow(non_camel_case_types)]
+pub type hvec1 = vec1<half>;
+#[allow(non_camel_case_types)]
 pub type ivec1 = vec1<c_int>;
 #[allow(non_camel_case_types)]
 pub type uvec1 = vec1<c_uint>;
@@ -19,401 +19,26 @@ pub type uvec1 = vec1<c_uint>;
 impl<T> vec1<T> {
     #[inline]
     pub const fn new(x: T) -> Self {
-        Self { x }
-    }
-    #[inline]
-    pub const fn as_ptr(&self) -> *const T {
-        &raw const *self as *const T
-    }
-    #[inline]
-    pub const fn as_mut_ptr(&mut self) -> *mut T{
-        &raw mut *self as *mut T
-    }
-    #[inline]
-    pub const fn len(&self) -> usize {
-        1
-    }
-    #[inline]
-    pub const fn as_slice(&self) -> &[T] {
-        unsafe { slice::from_raw_parts(self.as_ptr(), self.len()) }
-    }
-    #[inline]
-    pub const fn as_mut_slice(&mut self) -> &mut [T] {
-        unsafe { slice::from_raw_parts_mut(self.as_mut_ptr(), self.len()) }
-    }
-}
-
-impl<T> Deref for vec1<T> {
-    type Target = [T];
-
-    #[inline]
-    fn deref(&self) -> &[T] {
-        self.as_slice()
+        Self([x])
     }
 }
 
-impl<T> DerefMut for vec1<T> {
-    #[inline]
-    fn deref_mut(&mut self) -> &mut [T] {
-        self.as_mut_slice()
-    }
+impl<T: Number> vec1<T> {
+    pub const X: Self = Self([T::ONE]);
 }
 
-impl<T, I: SliceIndex<[T]>> Index<I> for vec1<T> {
-    type Output = I::Output;
+impl<T> Deref for vec1<T> {
+    type Target = X<T>;
 
     #[inline]
-    fn index(&self, index: I) -> &Self::Output {
-        Index::index(&**self, index)
+    fn deref(&self) -> &X<T> {
+        unsafe { &*(self.as_ptr() as *const X<T>) }
     }
 }
 
-impl<T, I: SliceIndex<[T]>> IndexMut<I> for vec1<T> {
+impl<T> DerefMut for vec1<T> {
     #[inline]
-    fn index_mut(&mut self, index: I) -> &mut Self::Output {
-        IndexMut::index_mut(&mut **self, index)
-    }
-}
-
-macro_rules! zero_impl {
-    ($($t:ty)*) => ($(
-        impl vec1<$t> {
-            #[inline]
-            pub const fn zero() -> Self {
-                Self { x: 0 as $t }
-            }
-        }
-    )*)
-}
-
-zero_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
-
-macro_rules! unop_impl {
-    (impl $imp:ident, $method:ident for $t:ty) => {
-        impl $imp for vec1<$t> {
-            type Output = vec1<$t>;
-
-            #[inline]
-            fn $method(self) -> vec1<$t> {
-                vec1 {
-                    x: $imp::$method(self.x),
-                }
-            }
-        }
-
-        forward_ref_unop! { impl $imp, $method for vec1<$t> }
+    fn deref_mut(&mut self) -> &mut X<T> {
+        unsafe { &mut *(self.as_mut_ptr() as *mut X<T>) }
     }
 }
-
-macro_rules! binop_impl {
-    (impl $imp:ident, $method: ident for $t:ty, $u:ty) => {
-        impl $imp<$u> for vec1<$t> {
-            type Output = vec1<$t>;
-
-            #[inline]
-            fn $method(self, other: $u) -> vec1<$t> {
-                vec1 {
-                    x: $imp::$method(self.x, other),
-                }
-            }
-        }
-        
-        forward_ref_binop! { impl $imp, $method for vec1<$t>, $u }
-
-        impl $imp<vec1<$u>> for $t {
-            type Output = vec1<$t>;
-
-            #[inline]
-            fn $method(self, other: vec1<$u>) -> vec1<$t> {
-                vec1 {
-                    x: $imp::$method(self, other.x),
-                }
-            }
-        }
-
-        forward_ref_binop! { impl $imp, $method for $t, vec1<$u> }
-    
-        impl $imp<vec1<$u>> for vec1<$t> {
-            type Output = vec1<$t>;
-
-            #[inline]
-            fn $method(self, other: vec1<$u>) -> vec1<$t> {
-                vec1 {
-                    x: $imp::$method(self.x, other.x),
-                }
-            }
-        }
-
-        forward_ref_binop! { impl $imp, $method for vec1<$t>, vec1<$u> }
-    }
-}
-
-macro_rules! op_assign_impl {
-    (impl $imp:ident, $method:ident for $t:ty, $u:ty) => {
-        impl $imp<$u> for vec1<$t> {
-            #[inline]
-            fn $method(&mut self, other: $u) {
-                $imp::$method(&mut self.x, other);
-            }
-        }
-
-        forward_ref_op_assign! { impl $imp, $method for vec1<$t>, $u }
-
-        impl $imp<vec1<$u>> for vec1<$t> {
-            #[inline]
-            fn $method(&mut self, other: vec1<$u>) {
-                $imp::$method(&mut self.x, other.x);
-            }
-        }
-
-        forward_ref_op_assign! { impl $imp, $method for vec1<$t>, vec1<$u> }
-    }
-}
-
-macro_rules! add_assign_impl {
-    ($($t:ty)+) => ($(
-        op_assign_impl! { impl AddAssign, add_assign for $t, $t }
-    )+)
-}
-
-add_assign_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
-
-macro_rules! sub_assign_impl {
-    ($($t:ty)+) => ($(
-        op_assign_impl! { impl SubAssign, sub_assign for $t, $t }
-    )+)
-}
-
-sub_assign_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
-
-macro_rules! mul_assign_impl {
-    ($($t:ty)+) => ($(
-        op_assign_impl! { impl MulAssign, mul_assign for $t, $t }
-    )+)
-}
-
-mul_assign_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
-
-macro_rules! div_assign_impl {
-    ($($t:ty)+) => ($(
-        op_assign_impl! { impl DivAssign, div_assign for $t, $t }
-    )+)
-}
-
-div_assign_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
-
-macro_rules! rem_assign_impl {
-    ($($t:ty)+) => ($(
-        op_assign_impl! { impl RemAssign, rem_assign for $t, $t }
-    )+)
-}
-
-rem_assign_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
-
-macro_rules! bitand_assign_impl {
-    ($($t:ty)+) => ($(
-        op_assign_impl! { impl BitAndAssign, bitand_assign for $t, $t }
-    )+)
-}
-
-bitand_assign_impl! { bool usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
-
-macro_rules! bitor_assign_impl {
-    ($($t:ty)+) => ($(
-        op_assign_impl! { impl BitOrAssign, bitor_assign for $t, $t }
-    )+)
-}
-
-bitor_assign_impl! { bool usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
-
-macro_rules! bitxor_assign_impl {
-    ($($t:ty)+) => ($(
-        op_assign_impl! { impl BitXorAssign, bitxor_assign for $t, $t }
-    )+)
-}
-
-bitxor_assign_impl! { bool usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
-
-macro_rules! shl_assign_impl {
-    ($t:ty, $f:ty) => {
-        op_assign_impl! { impl ShlAssign, shl_assign for $t, $f }
-    };
-}
-
-macro_rules! shl_assign_impl_all {
-    ($($t:ty)*) => ($(
-        shl_assign_impl! { $t, u8 }
-        shl_assign_impl! { $t, u16 }
-        shl_assign_impl! { $t, u32 }
-        shl_assign_impl! { $t, u64 }
-        shl_assign_impl! { $t, u128 }
-        shl_assign_impl! { $t, usize }
-
-        shl_assign_impl! { $t, i8 }
-        shl_assign_impl! { $t, i16 }
-        shl_assign_impl! { $t, i32 }
-        shl_assign_impl! { $t, i64 }
-        shl_assign_impl! { $t, i128 }
-        shl_assign_impl! { $t, isize }
-    )*)
-}
-
-shl_assign_impl_all! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
-
-macro_rules! shr_assign_impl {
-    ($t:ty, $f:ty) => {
-        op_assign_impl! { impl ShrAssign, shr_assign for $t, $f }
-    };
-}
-
-macro_rules! shr_assign_impl_all {
-    ($($t:ty)*) => ($(
-        shr_assign_impl! { $t, u8 }
-        shr_assign_impl! { $t, u16 }
-        shr_assign_impl! { $t, u32 }
-        shr_assign_impl! { $t, u64 }
-        shr_assign_impl! { $t, u128 }
-        shr_assign_impl! { $t, usize }
-
-        shr_assign_impl! { $t, i8 }
-        shr_assign_impl! { $t, i16 }
-        shr_assign_impl! { $t, i32 }
-        shr_assign_impl! { $t, i64 }
-        shr_assign_impl! { $t, i128 }
-        shr_assign_impl! { $t, isize }
-    )*)
-}
-
-shr_assign_impl_all! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
-
-macro_rules! neg_impl {
-    ($($t:ty)*) => ($(
-        unop_impl! { impl Neg, neg for $t }
-    )*)
-}
-
-neg_impl! { isize i8 i16 i32 i64 i128 f32 f64 }
-
-macro_rules! add_impl {
-    ($($t:ty)*) => ($(
-        binop_impl! { impl Add, add for $t, $t }
-    )*)
-}
-
-add_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
-
-macro_rules! sub_impl {
-    ($($t:ty)*) => ($(
-        binop_impl! { impl Sub, sub for $t, $t }
-    )*)
-}
-
-sub_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
-
-macro_rules! mul_impl {
-    ($($t:ty)*) => ($(
-        binop_impl! { impl Mul, mul for $t, $t }
-    )*)
-}
-
-mul_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
-
-macro_rules! div_impl {
-    ($($t:ty)*) => ($(
-        binop_impl! { impl Div, div for $t, $t }
-    )*)
-}
-
-div_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
-
-macro_rules! rem_impl {
-    ($($t:ty)*) => ($(
-        binop_impl! { impl Rem, rem for $t, $t }
-    )*)
-}
-
-rem_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
-
-macro_rules! bitand_impl {
-    ($($t:ty)*) => ($(
-        binop_impl! { impl BitAnd, bitand for $t, $t }
-    )*)
-}
-
-bitand_impl! { bool usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
-
-macro_rules! bitor_impl {
-    ($($t:ty)*) => ($(
-        binop_impl! { impl BitOr, bitor for $t, $t }
-    )*)
-}
-
-bitor_impl! { bool usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
-
-macro_rules! bitxor_impl {
-    ($($t:ty)*) => ($(
-        binop_impl! { impl BitXor, bitxor for $t, $t }
-    )*)
-}
-
-bitxor_impl! { bool usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
-
-macro_rules! shl_impl {
-    ($t:ty, $f:ty) => {
-        binop_impl! { impl Shl, shl for $t, $f }
-    };
-}
-
-macro_rules! shl_impl_all {
-    ($($t:ty)*) => ($(
-        shl_impl! { $t, u8 }
-        shl_impl! { $t, u16 }        
-        shl_impl! { $t, u32 }
-        shl_impl! { $t, u64 }
-        shl_impl! { $t, u128 }
-        shl_impl! { $t, usize }
-
-        shl_impl! { $t, i8 }
-        shl_impl! { $t, i16 }
-        shl_impl! { $t, i32 }
-        shl_impl! { $t, i64 }
-        shl_impl! { $t, i128 }
-        shl_impl! { $t, isize }
-    )*)
-}
-
-shl_impl_all! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
-
-macro_rules! shr_impl {
-    ($t:ty, $f:ty) => {
-        binop_impl! { impl Shr, shr for $t, $f }
-    };
-}
-
-macro_rules! shr_impl_all {
-    ($($t:ty)*) => ($(
-        shr_impl! { $t, u8 }
-        shr_impl! { $t, u16 }        
-        shr_impl! { $t, u32 }
-        shr_impl! { $t, u64 }
-        shr_impl! { $t, u128 }
-        shr_impl! { $t, usize }
-
-        shr_impl! { $t, i8 }
-        shr_impl! { $t, i16 }
-        shr_impl! { $t, i32 }
-        shr_impl! { $t, i64 }
-        shr_impl! { $t, i128 }
-        shr_impl! { $t, isize }
-    )*)
-}
-
-shr_impl_all! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
-
-macro_rules! not_impl {
-    ($($t:ty)*) => ($(
-        unop_impl! { impl Not, not for $t }
-    )*)
-}
-
-not_impl! { bool usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
@@ -0,0 +1,433 @@
+use core::{array, ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign, Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign}};
+
+use crate::{half, mat4, vec3, vec4, Float, Integer, Number, Scalar, SignedNumber};
+
+// Structure of arrays batches, one array per component, with the operator
+// set of the vector types applied lane by lane.
+
+// Summed across whole component arrays, in the order of the vector dot
+// products.
+macro_rules! dot_impl {
+    ($name:ident, $lanes:literal, [$x:ident $y:ident $z:ident]) => {
+        impl<T: Number> $name<T> {
+            #[inline]
+            pub fn dot(self, other: Self) -> [T; $lanes] {
+                let [$x, $y, $z] = [(self.$x, other.$x), (self.$y, other.$y), (self.$z, other.$z)]
+                    .map(|(a, b)| array::from_fn::<T, $lanes, _>(|i| a[i] * b[i]));
+                array::from_fn(|i| ($x[i] + $y[i]) + $z[i])
+            }
+        }
+    };
+    ($name:ident, $lanes:literal, [$x:ident $y:ident $z:ident $w:ident]) => {
+        impl<T: Number> $name<T> {
+            #[inline]
+            pub fn dot(self, other: Self) -> [T; $lanes] {
+                let [$x, $y, $z, $w] = [(self.$x, other.$x), (self.$y, other.$y), (self.$z, other.$z), (self.$w, other.$w)]
+                    .map(|(a, b)| array::from_fn::<T, $lanes, _>(|i| a[i] * b[i]));
+                array::from_fn(|i| ($x[i] + $y[i]) + ($z[i] + $w[i]))
+            }
+        }
+    };
+}
+
+macro_rules! batch_struct {
+    ($name:ident, $vec:ident, $lanes:literal, [$($c:ident)*]) => {
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+        #[repr(C)]
+        pub struct $name<T = f32> {
+            $(pub $c: [T; $lanes],)*
+        }
+
+        impl<T> $name<T> {
+            #[inline]
+            pub const fn new($($c: [T; $lanes]),*) -> Self {
+                Self { $($c),* }
+            }
+            #[inline]
+            pub const fn lanes(&self) -> usize {
+                $lanes
+            }
+        }
+
+        impl<T: Copy> $name<T> {
+            #[inline]
+            pub const fn splat(v: $vec<T>) -> Self {
+                let [$($c),*] = v.0;
+                Self { $($c: [$c; $lanes]),* }
+            }
+            #[inline]
+            pub const fn lane(&self, index: usize) -> $vec<T> {
+                $vec::new($(self.$c[index]),*)
+            }
+            #[inline]
+            pub const fn set_lane(&mut self, index: usize, v: $vec<T>) {
+                let [$($c),*] = v.0;
+                $(self.$c[index] = $c;)*
+            }
+            // Both panic unless the slice holds exactly one vector per lane,
+            // like copy_from_slice.
+            #[inline]
+            pub fn pack(src: &[$vec<T>]) -> Self {
+                let src: &[$vec<T>; $lanes] = src.try_into().expect("slice length must match the lane count");
+                Self::from(*src)
+            }
+            #[inline]
+            pub fn unpack(&self, dst: &mut [$vec<T>]) {
+                dst.copy_from_slice(&<[$vec<T>; $lanes]>::from(*self));
+            }
+        }
+
+        impl<T: Copy> From<[$vec<T>; $lanes]> for $name<T> {
+            #[inline]
+            fn from(v: [$vec<T>; $lanes]) -> Self {
+                Self { $($c: array::from_fn(|i| v[i].$c)),* }
+            }
+        }
+
+        impl<T: Copy> From<$name<T>> for [$vec<T>; $lanes] {
+            #[inline]
+            fn from(v: $name<T>) -> Self {
+                array::from_fn(|i| v.lane(i))
+            }
+        }
+
+        impl<T: Number> $name<T> {
+            #[inline]
+            pub const fn zero() -> Self {
+                Self { $($c: [T::ZERO; $lanes]),* }
+            }
+        }
+
+        dot_impl! { $name, $lanes, [$($c)*] }
+
+        impl<T: Float> $name<T> {
+            #[inline]
+            pub fn length(self) -> [T; $lanes] {
+                self.dot(self).map(T::sqrt)
+            }
+
+            #[inline]
+            pub fn normalize(self) -> Self {
+                let inv_length = self.length().map(|l| T::ONE / l);
+                Self { $($c: array::from_fn(|i| self.$c[i] * inv_length[i])),* }
+            }
+        }
+    }
+}
+
+macro_rules! unop_impl {
+    (impl[$($g:tt)*] $imp:ident, $method:ident for $name:ident, [$($c:ident)*]) => {
+        impl<$($g)*> $imp for $name<T> {
+            type Output = $name<T>;
+
+            #[inline]
+            fn $method(self) -> $name<T> {
+                $name { $($c: self.$c.map($imp::$method)),* }
+            }
+        }
+
+        forward_ref_unop! { impl[$($g)*] $imp, $method for $name<T> }
+    }
+}
+
+macro_rules! binop_impl {
+    (impl[$($g:tt)*] $imp:ident, $method:ident for $name:ident, $vec:ident, $t:ident, $u:ident, [$($c:ident)*]) => {
+        impl<$($g)*> $imp<$u> for $name<$t> {
+            type Output = $name<$t>;
+
+            #[inline]
+            fn $method(self, other: $u) -> $name<$t> {
+                $name { $($c: self.$c.map(|x| $imp::$method(x, other))),* }
+            }
+        }
+
+        forward_ref_binop! { impl[$($g)*] $imp, $method for $name<$t>, $u }
+
+        impl<$($g)*> $imp<$vec<$u>> for $name<$t> {
+            type Output = $name<$t>;
+
+            #[inline]
+            fn $method(self, other: $vec<$u>) -> $name<$t> {
+                $name { $($c: self.$c.map(|x| $imp::$method(x, other.$c))),* }
+            }
+        }
+
+        forward_ref_binop! { impl[$($g)*] $imp, $method for $name<$t>, $vec<$u> }
+
+        impl<$($g)*> $imp<$name<$u>> for $vec<$t> {
+            type Output = $name<$t>;
+
+            #[inline]
+            fn $method(self, other: $name<$u>) -> $name<$t> {
+                $name { $($c: other.$c.map(|x| $imp::$method(self.$c, x))),* }
+            }
+        }
+
+        forward_ref_binop! { impl[$($g)*] $imp, $method for $vec<$t>, $name<$u> }
+
+        impl<$($g)*> $imp<$name<$u>> for $name<$t> {
+            type Output = $name<$t>;
+
+            #[inline]
+            fn $method(self, other: $name<$u>) -> $name<$t> {
+                $name { $($c: array::from_fn(|i| $imp::$method(self.$c[i], other.$c[i]))),* }
+            }
+        }
+
+        forward_ref_binop! { impl[$($g)*] $imp, $method for $name<$t>, $name<$u> }
+    }
+}
+
+macro_rules! op_assign_impl {
+    (impl[$($g:tt)*] $imp:ident, $method:ident for $name:ident, $vec:ident, $t:ident, $u:ident, [$($c:ident)*]) => {
+        impl<$($g)*> $imp<$u> for $name<$t> {
+            #[inline]
+            fn $method(&mut self, other: $u) {
+                $(
+                    for x in &mut self.$c {
+                        $imp::$method(x, other);
+                    }
+                )*
+            }
+        }
+
+        forward_ref_op_assign! { impl[$($g)*] $imp, $method for $name<$t>, $u }
+
+        impl<$($g)*> $imp<$vec<$u>> for $name<$t> {
+            #[inline]
+            fn $method(&mut self, other: $vec<$u>) {
+                $(
+                    for x in &mut self.$c {
+                        $imp::$method(x, other.$c);
+                    }
+                )*
+            }
+        }
+
+        forward_ref_op_assign! { impl[$($g)*] $imp, $method for $name<$t>, $vec<$u> }
+
+        impl<$($g)*> $imp<$name<$u>> for $name<$t> {
+            #[inline]
+            fn $method(&mut self, other: $name<$u>) {
+                $(
+                    for (x, y) in self.$c.iter_mut().zip(other.$c) {
+                        $imp::$method(x, y);
+                    }
+                )*
+            }
+        }
+
+        forward_ref_op_assign! { impl[$($g)*] $imp, $method for $name<$t>, $name<$u> }
+    }
+}
+
+macro_rules! arith_impl {
+    (impl[$($g:tt)*] $imp:ident, $method:ident, $imp_assign:ident, $method_assign:ident for $name:ident, $vec:ident, $t:ident, $u:ident, $cs:tt) => {
+        binop_impl! { impl[$($g)*] $imp, $method for $name, $vec, $t, $u, $cs }
+        op_assign_impl! { impl[$($g)*] $imp_assign, $method_assign for $name, $vec, $t, $u, $cs }
+    }
+}
+
+macro_rules! shift_impl_all {
+    ($name:ident, $vec:ident, $cs:tt, $($u:ident)*) => ($(
+        arith_impl! { impl[T: Integer + Shl<$u, Output = T> + ShlAssign<$u>] Shl, shl, ShlAssign, shl_assign for $name, $vec, T, $u, $cs }
+        arith_impl! { impl[T: Integer + Shr<$u, Output = T> + ShrAssign<$u>] Shr, shr, ShrAssign, shr_assign for $name, $vec, T, $u, $cs }
+    )*)
+}
+
+macro_rules! scalar_binop_impl {
+    (impl $imp:ident, $method:ident for $name:ident, $t:ty, $u:ty, [$($c:ident)*]) => {
+        impl $imp<$name<$u>> for $t {
+            type Output = $name<$t>;
+
+            #[inline]
+            fn $method(self, other: $name<$u>) -> $name<$t> {
+                $name { $($c: other.$c.map(|x| $imp::$method(self, x))),* }
+            }
+        }
+
+        forward_ref_binop! { impl $imp, $method for $t, $name<$u> }
+    }
+}
+
+macro_rules! scalar_arith_impl {
+    ($name:ident, $cs:tt, $($t:ty)*) => ($(
+        scalar_binop_impl! { impl Add, add for $name, $t, $t, $cs }
+        scalar_binop_impl! { impl Sub, sub for $name, $t, $t, $cs }
+        scalar_binop_impl! { impl Mul, mul for $name, $t, $t, $cs }
+        scalar_binop_impl! { impl Div, div for $name, $t, $t, $cs }
+        scalar_binop_impl! { impl Rem, rem for $name, $t, $t, $cs }
+    )*)
+}
+
+macro_rules! scalar_bit_impl {
+    ($name:ident, $cs:tt, $($t:ty)*) => ($(
+        scalar_binop_impl! { impl BitAnd, bitand for $name, $t, $t, $cs }
+        scalar_binop_impl! { impl BitOr, bitor for $name, $t, $t, $cs }
+        scalar_binop_impl! { impl BitXor, bitxor for $name, $t, $t, $cs }
+    )*)
+}
+
+macro_rules! scalar_shift_impl_all {
+    ($name:ident, $cs:tt, $($t:ty)*) => ($(
+        scalar_shift_impl! { $name, $cs, $t, u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
+    )*)
+}
+
+macro_rules! scalar_shift_impl {
+    ($name:ident, $cs:tt, $t:ty, $($u:ty)*) => ($(
+        scalar_binop_impl! { impl Shl, shl for $name, $t, $u, $cs }
+        scalar_binop_impl! { impl Shr, shr for $name, $t, $u, $cs }
+    )*)
+}
+
+macro_rules! batch_impl {
+    ($name:ident, $vec:ident, $lanes:literal, $cs:tt) => {
+        batch_struct! { $name, $vec, $lanes, $cs }
+
+        arith_impl! { impl[T: Number] Add, add, AddAssign, add_assign for $name, $vec, T, T, $cs }
+        arith_impl! { impl[T: Number] Sub, sub, SubAssign, sub_assign for $name, $vec, T, T, $cs }
+        arith_impl! { impl[T: Number] Mul, mul, MulAssign, mul_assign for $name, $vec, T, T, $cs }
+        arith_impl! { impl[T: Number] Div, div, DivAssign, div_assign for $name, $vec, T, T, $cs }
+        arith_impl! { impl[T: Number] Rem, rem, RemAssign, rem_assign for $name, $vec, T, T, $cs }
+
+        arith_impl! { impl[T: Scalar + BitAnd<Output = T> + BitAndAssign] BitAnd, bitand, BitAndAssign, bitand_assign for $name, $vec, T, T, $cs }
+        arith_impl! { impl[T: Scalar + BitOr<Output = T> + BitOrAssign] BitOr, bitor, BitOrAssign, bitor_assign for $name, $vec, T, T, $cs }
+        arith_impl! { impl[T: Scalar + BitXor<Output = T> + BitXorAssign] BitXor, bitxor, BitXorAssign, bitxor_assign for $name, $vec, T, T, $cs }
+
+        shift_impl_all! { $name, $vec, $cs, u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
+
+        unop_impl! { impl[T: SignedNumber] Neg, neg for $name, $cs }
+        unop_impl! { impl[T: Scalar + Not<Output = T>] Not, not for $name, $cs }
+
+        scalar_arith_impl! { $name, $cs, usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 half }
+        scalar_bit_impl! { $name, $cs, bool usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+        scalar_shift_impl_all! { $name, $cs, u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
+    }
+}
+
+batch_impl! { vec3x4, vec3, 4, [x y z] }
+batch_impl! { vec3x8, vec3, 8, [x y z] }
+batch_impl! { vec4x4, vec4, 4, [x y z w] }
+batch_impl! { vec4x8, vec4, 8, [x y z w] }
+
+macro_rules! cross_impl {
+    ($name:ident) => {
+        impl<T: Number> $name<T> {
+            #[inline]
+            pub fn cross(self, other: Self) -> Self {
+                Self {
+                    x: array::from_fn(|i| self.y[i] * other.z[i] - other.y[i] * self.z[i]),
+                    y: array::from_fn(|i| self.z[i] * other.x[i] - other.z[i] * self.x[i]),
+                    z: array::from_fn(|i| self.x[i] * other.y[i] - other.x[i] * self.y[i]),
+                }
+            }
+        }
+    }
+}
+
+cross_impl! { vec3x4 }
+cross_impl! { vec3x8 }
+
+macro_rules! transform_impl {
+    ($name:ident) => {
+        impl<T: Number> Mul<$name<T>> for mat4<T> {
+            type Output = $name<T>;
+
+            #[inline]
+            fn mul(self, other: $name<T>) -> $name<T> {
+                let m = &self;
+                $name {
+                    x: array::from_fn(|i| (m[0].x * other.x[i] + m[1].x * other.y[i]) + (m[2].x * other.z[i] + m[3].x * other.w[i])),
+                    y: array::from_fn(|i| (m[0].y * other.x[i] + m[1].y * other.y[i]) + (m[2].y * other.z[i] + m[3].y * other.w[i])),
+                    z: array::from_fn(|i| (m[0].z * other.x[i] + m[1].z * other.y[i]) + (m[2].z * other.z[i] + m[3].z * other.w[i])),
+                    w: array::from_fn(|i| (m[0].w * other.x[i] + m[1].w * other.y[i]) + (m[2].w * other.z[i] + m[3].w * other.w[i])),
+                }
+            }
+        }
+
+        forward_ref_binop! { impl[T: Number] Mul, mul for mat4<T>, $name<T> }
+    }
+}
+
+transform_impl! { vec4x4 }
+transform_impl! { vec4x8 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{mat4, uvec3, vec3, vec3x4, vec3x8, vec4, vec4x4, vec4x8};
+
+    fn points() -> [vec3<u32>; 4] {
+        [uvec3::new(1, 2, 3), uvec3::new(4, 5, 6), uvec3::new(7, 8, 9), uvec3::new(10, 11, 12)]
+    }
+
+    #[test]
+    fn operators_match_vectors() {
+        let a = vec3x4::from(points());
+        let b = vec3x4::splat(uvec3::new(6, 3, 5));
+        let results = [a + b, a - 1, a * b, a / b, a % b, a & b, a | 8, a ^ b, a << 2u8, a >> uvec3::new(1, 2, 3), !a, 3 * a];
+        for i in 0..4 {
+            let (x, y) = (a.lane(i), b.lane(i));
+            let expected = [x + y, x - 1, x * y, x / y, x % y, x & y, x | 8, x ^ y, x << 2u8, x >> uvec3::new(1, 2, 3), !x, 3 * x];
+            for (result, expected) in results.iter().zip(expected) {
+                assert_eq!(result.lane(i), expected);
+            }
+        }
+
+        let mut c = a;
+        c <<= 1u32;
+        c |= b;
+        c ^= uvec3::new(1, 1, 1);
+        c -= a;
+        for i in 0..4 {
+            assert_eq!(c.lane(i), (((a.lane(i) << 1u32) | b.lane(i)) ^ uvec3::new(1, 1, 1)) - a.lane(i));
+        }
+        assert_eq!(-vec3x4::from(points().map(|p| p.map(|x| x as i32))).lane(2), vec3::new(-7, -8, -9));
+    }
+
+    #[test]
+    fn pack_and_unpack() {
+        let points = points();
+        let batch = vec3x4::pack(&points);
+        assert_eq!(batch, vec3x4::from(points));
+
+        let mut unpacked = [uvec3::ZERO; 4];
+        batch.unpack(&mut unpacked);
+        assert_eq!(unpacked, points);
+        assert_eq!(<[vec3<u32>; 4]>::from(batch), points);
+    }
+
+    #[test]
+    #[should_panic]
+    fn pack_panics_on_a_short_slice() {
+        vec3x4::pack(&points()[..3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unpack_panics_on_a_long_slice() {
+        vec4x8::<f32>::zero().unpack(&mut [vec4::ZERO; 9]);
+    }
+
+    #[test]
+    fn geometric_matches_vectors() {
+        let a: [vec4<f32>; 4] = core::array::from_fn(|i| vec4::new(i as f32 + 0.1, 1.3, -0.7 * i as f32, 2.9));
+        let m = mat4::new(vec4::new(1.0, 2.0, 3.0, 0.5), vec4::new(-1.0, 0.25, 4.0, 1.0), vec4::new(0.0, 7.0, -2.0, 1.5), vec4::new(3.0, 1.0, 1.0, 1.0));
+        let batch = vec4x4::from(a);
+        let (dot, normalized, transformed) = (batch.dot(batch), batch.normalize(), m * batch);
+        for i in 0..4 {
+            assert_eq!(dot[i].to_bits(), a[i].dot(a[i]).to_bits());
+            assert_eq!(normalized.lane(i), a[i].normalize());
+            assert_eq!(transformed.lane(i), m * a[i]);
+        }
+
+        let b: [vec3<f32>; 8] = core::array::from_fn(|i| vec3::new(1e8, 0.3 * i as f32, -1e8 + i as f32));
+        let batch = vec3x8::from(b);
+        let (dot, length) = (batch.dot(vec3x8::splat(vec3::ONE)), batch.length());
+        for i in 0..8 {
+            assert_eq!(dot[i].to_bits(), b[i].dot(vec3::ONE).to_bits());
+            assert_eq!(length[i].to_bits(), b[i].length().to_bits());
+        }
+    }
+}
@@ -0,0 +1,494 @@
+use core::{array, fmt, ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign}, slice::SliceIndex};
+
+use crate::{half, vec1, Bounded, Float, Integer, Number, Scalar, SignedNumber};
+
+// Built with new, from_array or From<[T; N]>; the components are exposed
+// as .x, .y, .z and .w fields through Deref, but cannot be named in a
+// struct literal.
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub struct Vector<T, const N: usize>(pub(crate) [T; N]);
+
+// Prints vec1 to vec4 with named fields, like the separate vector structs
+// did, and other sizes as the underlying array.
+impl<T: fmt::Debug, const N: usize> fmt::Debug for Vector<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const NAMES: [&str; 4] = ["vec1", "vec2", "vec3", "vec4"];
+        const FIELDS: [&str; 4] = ["x", "y", "z", "w"];
+        if !(1..=4).contains(&N) {
+            return f.debug_tuple("Vector").field(&self.0).finish();
+        }
+        let mut result = f.debug_struct(NAMES[N - 1]);
+        for (name, value) in FIELDS.iter().zip(&self.0) {
+            result.field(name, value);
+        }
+        result.finish()
+    }
+}
+
+impl<T, const N: usize> Vector<T, N> {
+    #[inline]
+    pub const fn from_array(value: [T; N]) -> Self {
+        Self(value)
+    }
+    #[inline]
+    pub const fn as_array(&self) -> &[T; N] {
+        &self.0
+    }
+    #[inline]
+    pub const fn as_mut_array(&mut self) -> &mut [T; N] {
+        &mut self.0
+    }
+    #[inline]
+    pub const fn as_ptr(&self) -> *const T {
+        self.0.as_ptr()
+    }
+    #[inline]
+    pub const fn as_mut_ptr(&mut self) -> *mut T {
+        self.0.as_mut_ptr()
+    }
+    #[inline]
+    pub const fn len(&self) -> usize {
+        N
+    }
+    #[inline]
+    pub const fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+    #[inline]
+    pub const fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+    #[inline]
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> Vector<U, N> {
+        Vector(self.0.map(f))
+    }
+}
+
+impl<T: Copy, const N: usize> Vector<T, N> {
+    #[inline]
+    pub const fn splat(value: T) -> Self {
+        Self([value; N])
+    }
+}
+
+impl<T: Number, const N: usize> Vector<T, N> {
+    pub const ZERO: Self = Self([T::ZERO; N]);
+    pub const ONE: Self = Self([T::ONE; N]);
+
+    #[inline]
+    pub const fn zero() -> Self {
+        Self([T::ZERO; N])
+    }
+}
+
+impl<T: Bounded, const N: usize> Vector<T, N> {
+    pub const MIN: Self = Self([T::MIN; N]);
+    pub const MAX: Self = Self([T::MAX; N]);
+}
+
+impl<T: Float, const N: usize> Vector<T, N> {
+    pub const NAN: Self = Self([T::NAN; N]);
+    pub const INFINITY: Self = Self([T::INFINITY; N]);
+    pub const NEG_INFINITY: Self = Self([T::NEG_INFINITY; N]);
+}
+
+impl<T: Default, const N: usize> Default for Vector<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self(array::from_fn(|_| T::default()))
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Vector<T, N> {
+    #[inline]
+    fn from(value: [T; N]) -> Self {
+        Self(value)
+    }
+}
+
+impl<T, const N: usize> From<Vector<T, N>> for [T; N] {
+    #[inline]
+    fn from(value: Vector<T, N>) -> Self {
+        value.0
+    }
+}
+
+impl<T, I: SliceIndex<[T]>, const N: usize> Index<I> for Vector<T, N> {
+    type Output = I::Output;
+
+    #[inline]
+    fn index(&self, index: I) -> &Self::Output {
+        Index::index(self.as_slice(), index)
+    }
+}
+
+impl<T, I: SliceIndex<[T]>, const N: usize> IndexMut<I> for Vector<T, N> {
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        IndexMut::index_mut(self.as_mut_slice(), index)
+    }
+}
+
+macro_rules! integer_arith_impl {
+    ($($method:ident)*) => {
+        impl<T: Integer, const N: usize> Vector<T, N> {
+            $(
+                #[inline]
+                pub fn $method(self, other: Self) -> Self {
+                    Vector(array::from_fn(|i| self.0[i].$method(other.0[i])))
+                }
+            )*
+        }
+    }
+}
+
+integer_arith_impl! {
+    wrapping_add wrapping_sub wrapping_mul wrapping_div wrapping_rem
+    saturating_add saturating_sub saturating_mul saturating_div
+}
+
+macro_rules! checked_arith_impl {
+    ($($method:ident)*) => {
+        impl<T: Integer, const N: usize> Vector<T, N> {
+            $(
+                #[inline]
+                pub fn $method(self, other: Self) -> Option<Self> {
+                    let mut result = self;
+                    for i in 0..N {
+                        result.0[i] = self.0[i].$method(other.0[i])?;
+                    }
+                    Some(result)
+                }
+            )*
+        }
+    }
+}
+
+checked_arith_impl! { checked_add checked_sub checked_mul checked_div checked_rem }
+
+macro_rules! overflowing_arith_impl {
+    ($($method:ident)*) => {
+        impl<T: Integer, const N: usize> Vector<T, N> {
+            $(
+                #[inline]
+                pub fn $method(self, other: Self) -> (Self, Vector<bool, N>) {
+                    let mut result = self;
+                    let mut overflow = Vector([false; N]);
+                    for i in 0..N {
+                        (result.0[i], overflow.0[i]) = self.0[i].$method(other.0[i]);
+                    }
+                    (result, overflow)
+                }
+            )*
+        }
+    }
+}
+
+overflowing_arith_impl! { overflowing_add overflowing_sub overflowing_mul overflowing_div overflowing_rem }
+
+impl<T: Integer, const N: usize> Vector<T, N> {
+    #[inline]
+    pub fn wrapping_neg(self) -> Self {
+        self.map(Integer::wrapping_neg)
+    }
+
+    #[inline]
+    pub fn checked_neg(self) -> Option<Self> {
+        let mut result = self;
+        for i in 0..N {
+            result.0[i] = self.0[i].checked_neg()?;
+        }
+        Some(result)
+    }
+
+    #[inline]
+    pub fn overflowing_neg(self) -> (Self, Vector<bool, N>) {
+        let mut result = self;
+        let mut overflow = Vector([false; N]);
+        for i in 0..N {
+            (result.0[i], overflow.0[i]) = self.0[i].overflowing_neg();
+        }
+        (result, overflow)
+    }
+}
+
+macro_rules! unop_impl {
+    (impl[$($g:tt)*] $imp:ident, $method:ident) => {
+        impl<$($g)*, const N: usize> $imp for Vector<T, N> {
+            type Output = Vector<T, N>;
+
+            #[inline]
+            fn $method(self) -> Vector<T, N> {
+                Vector(self.0.map($imp::$method))
+            }
+        }
+
+        forward_ref_unop! { impl[$($g)*, const N: usize] $imp, $method for Vector<T, N> }
+    }
+}
+
+macro_rules! binop_impl {
+    (impl[$($g:tt)*] $imp:ident, $method:ident for $t:ident, $u:ident) => {
+        impl<$($g)*, const N: usize> $imp<$u> for Vector<$t, N> {
+            type Output = Vector<$t, N>;
+
+            #[inline]
+            fn $method(self, other: $u) -> Vector<$t, N> {
+                Vector(self.0.map(|x| $imp::$method(x, other)))
+            }
+        }
+
+        forward_ref_binop! { impl[$($g)*, const N: usize] $imp, $method for Vector<$t, N>, $u }
+
+        impl<$($g)*, const N: usize> $imp<Vector<$u, N>> for Vector<$t, N> {
+            type Output = Vector<$t, N>;
+
+            #[inline]
+            fn $method(self, other: Vector<$u, N>) -> Vector<$t, N> {
+                Vector(array::from_fn(|i| $imp::$method(self.0[i], other.0[i])))
+            }
+        }
+
+        forward_ref_binop! { impl[$($g)*, const N: usize] $imp, $method for Vector<$t, N>, Vector<$u, N> }
+    }
+}
+
+macro_rules! scalar_binop_impl {
+    (impl $imp:ident, $method:ident for $t:ty, $u:ty) => {
+        impl<const N: usize> $imp<Vector<$u, N>> for $t {
+            type Output = Vector<$t, N>;
+
+            #[inline]
+            fn $method(self, other: Vector<$u, N>) -> Vector<$t, N> {
+                Vector(other.0.map(|x| $imp::$method(self, x)))
+            }
+        }
+
+        forward_ref_binop! { impl[const N: usize] $imp, $method for $t, Vector<$u, N> }
+    }
+}
+
+macro_rules! op_assign_impl {
+    (impl[$($g:tt)*] $imp:ident, $method:ident for $t:ident, $u:ident) => {
+        impl<$($g)*, const N: usize> $imp<$u> for Vector<$t, N> {
+            #[inline]
+            fn $method(&mut self, other: $u) {
+                for x in &mut self.0 {
+                    $imp::$method(x, other);
+                }
+            }
+        }
+
+        forward_ref_op_assign! { impl[$($g)*, const N: usize] $imp, $method for Vector<$t, N>, $u }
+
+        impl<$($g)*, const N: usize> $imp<Vector<$u, N>> for Vector<$t, N> {
+            #[inline]
+            fn $method(&mut self, other: Vector<$u, N>) {
+                for (x, y) in self.0.iter_mut().zip(other.0) {
+                    $imp::$method(x, y);
+                }
+            }
+        }
+
+        forward_ref_op_assign! { impl[$($g)*, const N: usize] $imp, $method for Vector<$t, N>, Vector<$u, N> }
+    }
+}
+
+// Broadcasting a vec1 is spelled out per size, as a generic impl would
+// overlap with the component-wise one when N is 1.
+macro_rules! broadcast_binop_impl {
+    (impl[$($g:tt)*] $imp:ident, $method:ident for $t:ident, $u:ident, $n:literal) => {
+        impl<$($g)*> $imp<vec1<$u>> for Vector<$t, $n> {
+            type Output = Vector<$t, $n>;
+
+            #[inline]
+            fn $method(self, other: vec1<$u>) -> Vector<$t, $n> {
+                $imp::$method(self, other.0[0])
+            }
+        }
+
+        forward_ref_binop! { impl[$($g)*] $imp, $method for Vector<$t, $n>, vec1<$u> }
+
+        impl<$($g)*> $imp<Vector<$u, $n>> for vec1<$t> {
+            type Output = Vector<$t, $n>;
+
+            #[inline]
+            fn $method(self, other: Vector<$u, $n>) -> Vector<$t, $n> {
+                Vector(other.0.map(|x| $imp::$method(self.0[0], x)))
+            }
+        }
+
+        forward_ref_binop! { impl[$($g)*] $imp, $method for vec1<$t>, Vector<$u, $n> }
+    }
+}
+
+macro_rules! broadcast_op_assign_impl {
+    (impl[$($g:tt)*] $imp:ident, $method:ident for $t:ident, $u:ident, $n:literal) => {
+        impl<$($g)*> $imp<vec1<$u>> for Vector<$t, $n> {
+            #[inline]
+            fn $method(&mut self, other: vec1<$u>) {
+                $imp::$method(self, other.0[0])
+            }
+        }
+
+        forward_ref_op_assign! { impl[$($g)*] $imp, $method for Vector<$t, $n>, vec1<$u> }
+    }
+}
+
+macro_rules! arith_impl {
+    (impl[$($g:tt)*] $imp:ident, $method:ident, $imp_assign:ident, $method_assign:ident for $t:ident, $u:ident) => {
+        binop_impl! { impl[$($g)*] $imp, $method for $t, $u }
+        op_assign_impl! { impl[$($g)*] $imp_assign, $method_assign for $t, $u }
+        broadcast_binop_impl! { impl[$($g)*] $imp, $method for $t, $u, 2 }
+        broadcast_binop_impl! { impl[$($g)*] $imp, $method for $t, $u, 3 }
+        broadcast_binop_impl! { impl[$($g)*] $imp, $method for $t, $u, 4 }
+        broadcast_op_assign_impl! { impl[$($g)*] $imp_assign, $method_assign for $t, $u, 2 }
+        broadcast_op_assign_impl! { impl[$($g)*] $imp_assign, $method_assign for $t, $u, 3 }
+        broadcast_op_assign_impl! { impl[$($g)*] $imp_assign, $method_assign for $t, $u, 4 }
+    }
+}
+
+arith_impl! { impl[T: Number] Add, add, AddAssign, add_assign for T, T }
+arith_impl! { impl[T: Number] Sub, sub, SubAssign, sub_assign for T, T }
+arith_impl! { impl[T: Number] Mul, mul, MulAssign, mul_assign for T, T }
+arith_impl! { impl[T: Number] Div, div, DivAssign, div_assign for T, T }
+arith_impl! { impl[T: Number] Rem, rem, RemAssign, rem_assign for T, T }
+
+arith_impl! { impl[T: Scalar + BitAnd<Output = T> + BitAndAssign] BitAnd, bitand, BitAndAssign, bitand_assign for T, T }
+arith_impl! { impl[T: Scalar + BitOr<Output = T> + BitOrAssign] BitOr, bitor, BitOrAssign, bitor_assign for T, T }
+arith_impl! { impl[T: Scalar + BitXor<Output = T> + BitXorAssign] BitXor, bitxor, BitXorAssign, bitxor_assign for T, T }
+
+macro_rules! shift_impl_all {
+    ($($u:ident)*) => ($(
+        arith_impl! { impl[T: Integer + Shl<$u, Output = T> + ShlAssign<$u>] Shl, shl, ShlAssign, shl_assign for T, $u }
+        arith_impl! { impl[T: Integer + Shr<$u, Output = T> + ShrAssign<$u>] Shr, shr, ShrAssign, shr_assign for T, $u }
+    )*)
+}
+
+shift_impl_all! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
+
+unop_impl! { impl[T: SignedNumber] Neg, neg }
+unop_impl! { impl[T: Scalar + Not<Output = T>] Not, not }
+
+macro_rules! scalar_arith_impl {
+    ($($t:ty)*) => ($(
+        scalar_binop_impl! { impl Add, add for $t, $t }
+        scalar_binop_impl! { impl Sub, sub for $t, $t }
+        scalar_binop_impl! { impl Mul, mul for $t, $t }
+        scalar_binop_impl! { impl Div, div for $t, $t }
+        scalar_binop_impl! { impl Rem, rem for $t, $t }
+    )*)
+}
+
+scalar_arith_impl! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 half }
+
+macro_rules! scalar_bit_impl {
+    ($($t:ty)*) => ($(
+        scalar_binop_impl! { impl BitAnd, bitand for $t, $t }
+        scalar_binop_impl! { impl BitOr, bitor for $t, $t }
+        scalar_binop_impl! { impl BitXor, bitxor for $t, $t }
+    )*)
+}
+
+scalar_bit_impl! { bool usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+
+macro_rules! scalar_shift_impl_all {
+    ($($t:ty)*) => ($(
+        scalar_shift_impl! { $t, u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
+    )*)
+}
+
+macro_rules! scalar_shift_impl {
+    ($t:ty, $($u:ty)*) => ($(
+        scalar_binop_impl! { impl Shl, shl for $t, $u }
+        scalar_binop_impl! { impl Shr, shr for $t, $u }
+    )*)
+}
+
+scalar_shift_impl_all! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::format;
+
+    use core::num::{Saturating, Wrapping};
+
+    use crate::{mat2x3, mat4, vec1, vec2, vec3, vec4, Vector};
+
+    #[test]
+    fn debug_names_fields() {
+        assert_eq!(format!("{:?}", vec1::new(1)), "vec1 { x: 1 }");
+        assert_eq!(format!("{:?}", vec2::new(1.5, -2.0)), "vec2 { x: 1.5, y: -2.0 }");
+        assert_eq!(format!("{:?}", vec3::new(true, false, true)), "vec3 { x: true, y: false, z: true }");
+        assert_eq!(format!("{:?}", vec4::new(1u8, 2, 3, 4)), "vec4 { x: 1, y: 2, z: 3, w: 4 }");
+        assert_eq!(format!("{:?}", Vector::from_array([1, 2, 3, 4, 5])), "Vector([1, 2, 3, 4, 5])");
+        assert_eq!(
+            format!("{:?}", mat2x3::new(vec3::new(1, 2, 3), vec3::new(4, 5, 6))),
+            "mat2x3 { value: [vec3 { x: 1, y: 2, z: 3 }, vec3 { x: 4, y: 5, z: 6 }] }");
+        assert!(format!("{:#?}", mat4::<f32>::identity()).starts_with("mat4 {\n    value: [\n        vec4 {\n            x: 1.0,"));
+    }
+
+    #[test]
+    fn wrapper_elements_overflow_like_scalars() {
+        let w = vec3::new(Wrapping(250u8), Wrapping(3), Wrapping(0));
+        assert_eq!(w + vec3::splat(Wrapping(10)), vec3::new(Wrapping(4), Wrapping(13), Wrapping(10)));
+        assert_eq!(w - vec3::splat(Wrapping(4)), vec3::new(Wrapping(246), Wrapping(255), Wrapping(252)));
+        assert_eq!(w * Wrapping(2), vec3::new(Wrapping(244), Wrapping(6), Wrapping(0)));
+        assert_eq!(-vec2::new(Wrapping(i8::MIN), Wrapping(5)), vec2::new(Wrapping(i8::MIN), Wrapping(-5)));
+
+        let s = vec3::new(Saturating(250u8), Saturating(3), Saturating(0));
+        assert_eq!(s + vec3::splat(Saturating(10)), vec3::new(Saturating(255), Saturating(13), Saturating(10)));
+        assert_eq!(s - vec3::splat(Saturating(4)), vec3::new(Saturating(246), Saturating(0), Saturating(0)));
+        assert_eq!(s * Saturating(2), vec3::new(Saturating(255), Saturating(6), Saturating(0)));
+        assert_eq!(-vec2::new(Saturating(i8::MIN), Saturating(5)), vec2::new(Saturating(i8::MAX), Saturating(-5)));
+    }
+
+    #[test]
+    fn overflow_per_lane() {
+        let (a, b) = (vec3::new(i8::MAX, i8::MIN, 5), vec3::new(1, -1, 1));
+        assert_eq!(a.wrapping_add(b), vec3::new(i8::MIN, i8::MAX, 6));
+        assert_eq!(a.saturating_add(b), vec3::new(i8::MAX, i8::MIN, 6));
+        assert_eq!(a.overflowing_add(b), (vec3::new(i8::MIN, i8::MAX, 6), vec3::new(true, true, false)));
+        assert_eq!(a.checked_add(b), None);
+        assert_eq!(a.checked_add(vec3::new(0, 0, 1)), Some(vec3::new(i8::MAX, i8::MIN, 6)));
+
+        // Each lane overflowing on its own makes the whole result None.
+        for lane in 0..3 {
+            let mut b = vec3::splat(0u8);
+            b.0[lane] = 1;
+            let a = vec3::splat(u8::MAX);
+            assert_eq!(a.checked_add(b), None);
+            assert_eq!(a.overflowing_add(b).1.0, core::array::from_fn(|i| i == lane));
+            assert_eq!(a.wrapping_add(b).0, core::array::from_fn(|i| if i == lane { 0 } else { u8::MAX }));
+            assert_eq!(a.saturating_add(b), a);
+        }
+
+        let (a, b) = (vec4::new(0u8, 1, 200, 7), vec4::new(1, 1, 2, 0));
+        assert_eq!(a.wrapping_sub(b), vec4::new(255, 0, 198, 7));
+        assert_eq!(a.saturating_sub(b), vec4::new(0, 0, 198, 7));
+        assert_eq!(a.overflowing_mul(b), (vec4::new(0, 1, 144, 0), vec4::new(false, false, true, false)));
+        assert_eq!(a.saturating_mul(b), vec4::new(0, 1, 255, 0));
+        assert_eq!(a.checked_mul(b), None);
+        assert_eq!(a.checked_div(b), None);
+        assert_eq!(a.checked_rem(b), None);
+        assert_eq!(a.checked_div(vec4::ONE), Some(a));
+
+        // Division only overflows for MIN / -1.
+        let (a, b) = (vec2::new(i32::MIN, i32::MIN), vec2::new(-1, 2));
+        assert_eq!(a.wrapping_div(b), vec2::new(i32::MIN, i32::MIN / 2));
+        assert_eq!(a.saturating_div(b), vec2::new(i32::MAX, i32::MIN / 2));
+        assert_eq!(a.overflowing_div(b), (vec2::new(i32::MIN, i32::MIN / 2), vec2::new(true, false)));
+        assert_eq!(a.wrapping_rem(b), vec2::new(0, 0));
+        assert_eq!(a.overflowing_rem(b), (vec2::new(0, 0), vec2::new(true, false)));
+        assert_eq!(a.checked_div(b), None);
+
+        let a = vec2::new(i16::MIN, 3);
+        assert_eq!(a.wrapping_neg(), vec2::new(i16::MIN, -3));
+        assert_eq!(a.overflowing_neg(), (vec2::new(i16::MIN, -3), vec2::new(true, false)));
+        assert_eq!(a.checked_neg(), None);
+        assert_eq!(vec2::new(1i16, 3).checked_neg(), Some(vec2::new(-1, -3)));
+    }
+}